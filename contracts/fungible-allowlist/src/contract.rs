@@ -5,7 +5,10 @@
 //! controlled token transfers by an admin who can allow or disallow specific
 //! accounts.
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, panic_with_error, symbol_short, Address, Env, String,
+    Vec,
+};
 use stellar_access::access_control::{self as access_control, AccessControl};
 use stellar_macros::{default_impl, only_role};
 use stellar_tokens::fungible::{
@@ -14,11 +17,22 @@ use stellar_tokens::fungible::{
     Base, FungibleToken,
 };
 
+/// Maximum number of accounts per `allow_users` and `disallow_users` call.
+pub const MAX_BATCH_SIZE: u32 = 40;
+
 #[contract]
 pub struct ExampleContract;
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    BatchTooLarge = 1,
+}
+
 #[contractimpl]
 impl ExampleContract {
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         e: &Env,
         admin: Address,
@@ -27,6 +41,7 @@ impl ExampleContract {
         name: String,
         symbol: String,
         decimals: u32,
+        allocations: Vec<(Address, i128)>,
     ) {
         Base::set_metadata(e, decimals, name, symbol);
 
@@ -40,6 +55,42 @@ impl ExampleContract {
 
         // Mint initial supply to the admin
         Base::mint(e, &admin, initial_supply);
+
+        // Allow and mint to every initial allocation recipient
+        for (recipient, amount) in allocations.iter() {
+            if !AllowList::allowed(e, &recipient) {
+                AllowList::allow_user(e, &recipient);
+            }
+            Base::mint(e, &recipient, amount);
+        }
+    }
+
+    /// Allows every account in `users`, skipping those already allowed.
+    #[only_role(operator, "manager")]
+    pub fn allow_users(e: &Env, users: Vec<Address>, operator: Address) {
+        check_batch_size(e, &users);
+        for user in users.iter() {
+            if !AllowList::allowed(e, &user) {
+                AllowList::allow_user(e, &user);
+            }
+        }
+    }
+
+    /// Disallows every account in `users`, skipping those not allowed.
+    #[only_role(operator, "manager")]
+    pub fn disallow_users(e: &Env, users: Vec<Address>, operator: Address) {
+        check_batch_size(e, &users);
+        for user in users.iter() {
+            if AllowList::allowed(e, &user) {
+                AllowList::disallow_user(e, &user);
+            }
+        }
+    }
+}
+
+fn check_batch_size(e: &Env, users: &Vec<Address>) {
+    if users.len() > MAX_BATCH_SIZE {
+        panic_with_error!(e, ExampleContractError::BatchTooLarge);
     }
 }
 
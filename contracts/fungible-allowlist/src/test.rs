@@ -1,8 +1,18 @@
 extern crate std;
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, Env, String, Vec,
+};
 
-use crate::contract::{ExampleContract, ExampleContractClient};
+use crate::contract::{ExampleContract, ExampleContractClient, MAX_BATCH_SIZE};
+
+// Per-transaction network limits used to size `MAX_BATCH_SIZE`. Each account
+// in a batch is one persistent entry write, so 40 accounts leave 10 of the 50
+// write entries for the contract instance and authorization nonces.
+const TX_MAX_INSTRUCTIONS: i64 = 100_000_000;
+const TX_MAX_WRITE_ENTRIES: u32 = 50;
+const TX_MAX_EVENTS_SIZE_BYTES: u32 = 16_384;
 
 fn create_client<'a>(
     e: &Env,
@@ -10,10 +20,39 @@ fn create_client<'a>(
     manager: &Address,
     initial_supply: &i128,
 ) -> ExampleContractClient<'a> {
-    let address = e.register(ExampleContract, (admin, manager, initial_supply));
+    create_client_with_allocations(e, admin, manager, initial_supply, Vec::new(e))
+}
+
+fn create_client_with_allocations<'a>(
+    e: &Env,
+    admin: &Address,
+    manager: &Address,
+    initial_supply: &i128,
+    allocations: Vec<(Address, i128)>,
+) -> ExampleContractClient<'a> {
+    let address = e.register(
+        ExampleContract,
+        (
+            admin,
+            manager,
+            initial_supply,
+            String::from_str(e, "My Token"),
+            String::from_str(e, "TKN"),
+            7u32,
+            allocations,
+        ),
+    );
     ExampleContractClient::new(e, &address)
 }
 
+fn generate_users(e: &Env, count: u32) -> Vec<Address> {
+    let mut users = Vec::new(e);
+    for _ in 0..count {
+        users.push_back(Address::generate(e));
+    }
+    users
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #113)")]
 fn cannot_transfer_before_allow() {
@@ -152,3 +191,132 @@ fn allowlist_approve_override_works() {
     client.approve(&user1, &user2, &transfer_amount, &1000);
     assert_eq!(client.allowance(&user1, &user2), transfer_amount);
 }
+
+#[test]
+fn allow_users_works() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, &1_000_000);
+
+    e.mock_all_auths();
+
+    let users = generate_users(&e, 3);
+    client.allow_users(&users, &manager);
+    for user in users.iter() {
+        assert!(client.allowed(&user));
+    }
+
+    // Allowed accounts can now receive tokens
+    client.transfer(&admin, &users.get(2).unwrap(), &1000);
+    assert_eq!(client.balance(&users.get(2).unwrap()), 1000);
+
+    client.disallow_users(&users, &manager);
+    for user in users.iter() {
+        assert!(!client.allowed(&user));
+    }
+}
+
+#[test]
+fn allow_users_skips_duplicates() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, &1_000_000);
+
+    e.mock_all_auths();
+
+    client.allow_user(&user1, &manager);
+
+    // user1 is already allowed and user2 is listed twice
+    let users = Vec::from_array(&e, [user1.clone(), user2.clone(), user2.clone()]);
+    client.allow_users(&users, &manager);
+
+    // Only user2 produces an event
+    assert_eq!(e.events().all().len(), 1);
+    assert!(client.allowed(&user1));
+    assert!(client.allowed(&user2));
+
+    // Disallowing skips accounts that are not allowed
+    let users = Vec::from_array(&e, [user2.clone(), user2.clone(), Address::generate(&e)]);
+    client.disallow_users(&users, &manager);
+    assert_eq!(e.events().all().len(), 1);
+    assert!(client.allowed(&user1));
+    assert!(!client.allowed(&user2));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn allow_users_requires_manager() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let outsider = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, &1_000_000);
+
+    e.mock_all_auths();
+
+    client.allow_users(&generate_users(&e, 2), &outsider);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn allow_users_rejects_oversized_batch() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, &1_000_000);
+
+    e.mock_all_auths();
+
+    client.allow_users(&generate_users(&e, MAX_BATCH_SIZE + 1), &manager);
+}
+
+#[test]
+fn allow_users_max_batch_fits_transaction_limits() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, &1_000_000);
+
+    e.mock_all_auths();
+
+    let users = generate_users(&e, MAX_BATCH_SIZE);
+    client.allow_users(&users, &manager);
+    let resources = e.cost_estimate().resources();
+    assert!(resources.instructions <= TX_MAX_INSTRUCTIONS);
+    assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES);
+    assert!(resources.contract_events_size_bytes <= TX_MAX_EVENTS_SIZE_BYTES);
+
+    client.disallow_users(&users, &manager);
+    let resources = e.cost_estimate().resources();
+    assert!(resources.instructions <= TX_MAX_INSTRUCTIONS);
+    assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES);
+    assert!(resources.contract_events_size_bytes <= TX_MAX_EVENTS_SIZE_BYTES);
+}
+
+#[test]
+fn initial_allocations_are_minted_and_allowed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let team = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let mut allocations = Vec::new(&e);
+    allocations.push_back((team.clone(), 200));
+    allocations.push_back((treasury.clone(), 300));
+    let client = create_client_with_allocations(&e, &admin, &manager, &1_000, allocations);
+
+    assert_eq!(client.balance(&admin), 1_000);
+    assert_eq!(client.balance(&team), 200);
+    assert_eq!(client.balance(&treasury), 300);
+    assert_eq!(client.total_supply(), 1_500);
+
+    // Recipients can move their allocation right away
+    assert!(client.allowed(&team));
+    client.transfer(&team, &treasury, &50);
+    assert_eq!(client.balance(&treasury), 350);
+}
@@ -5,7 +5,10 @@
 //! controlled token transfers by an admin who can block or unblock specific
 //! accounts.
 
-use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Address, Env, String};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, panic_with_error, symbol_short, Address, Env, String,
+    Vec,
+};
 use stellar_access::access_control::{self as access_control, AccessControl};
 use stellar_macros::{default_impl, only_role};
 use stellar_tokens::fungible::{
@@ -13,6 +16,9 @@ use stellar_tokens::fungible::{
     Base, FungibleToken,
 };
 
+/// Maximum number of accounts per `block_users` and `unblock_users` call.
+pub const MAX_BATCH_SIZE: u32 = 40;
+
 #[contract]
 pub struct ExampleContract;
 
@@ -21,10 +27,12 @@ pub struct ExampleContract;
 #[repr(u32)]
 pub enum ExampleContractError {
     Unauthorized = 1,
+    BatchTooLarge = 2,
 }
 
 #[contractimpl]
 impl ExampleContract {
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         e: &Env,
         admin: Address,
@@ -33,6 +41,7 @@ impl ExampleContract {
         name: String,
         symbol: String,
         decimals: u32,
+        allocations: Vec<(Address, i128)>,
     ) {
         Base::set_metadata(e, decimals, name, symbol);
 
@@ -44,6 +53,39 @@ impl ExampleContract {
 
         // Mint initial supply to the admin
         Base::mint(e, &admin, initial_supply);
+
+        // Mint every initial allocation
+        for (recipient, amount) in allocations.iter() {
+            Base::mint(e, &recipient, amount);
+        }
+    }
+
+    /// Blocks every account in `users`, skipping those already blocked.
+    #[only_role(operator, "manager")]
+    pub fn block_users(e: &Env, users: Vec<Address>, operator: Address) {
+        check_batch_size(e, &users);
+        for user in users.iter() {
+            if !BlockList::blocked(e, &user) {
+                BlockList::block_user(e, &user);
+            }
+        }
+    }
+
+    /// Unblocks every account in `users`, skipping those not blocked.
+    #[only_role(operator, "manager")]
+    pub fn unblock_users(e: &Env, users: Vec<Address>, operator: Address) {
+        check_batch_size(e, &users);
+        for user in users.iter() {
+            if BlockList::blocked(e, &user) {
+                BlockList::unblock_user(e, &user);
+            }
+        }
+    }
+}
+
+fn check_batch_size(e: &Env, users: &Vec<Address>) {
+    if users.len() > MAX_BATCH_SIZE {
+        panic_with_error!(e, ExampleContractError::BatchTooLarge);
     }
 }
 
@@ -7,7 +7,7 @@
 //! **IMPORTANT**: this example is for demonstration purposes, and authorization
 //! is not taken into consideration
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 use stellar_tokens::fungible::{
     capped::{check_cap, set_cap},
     Base, FungibleToken,
@@ -18,6 +18,7 @@ pub struct ExampleContract;
 
 #[contractimpl]
 impl ExampleContract {
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         e: &Env,
         admin: Address,
@@ -27,6 +28,7 @@ impl ExampleContract {
         name: String,
         symbol: String,
         decimals: u32,
+        allocations: Vec<(Address, i128)>,
     ) {
         Base::set_metadata(e, decimals, name, symbol);
         set_cap(e, cap);
@@ -34,6 +36,12 @@ impl ExampleContract {
         // Mint initial supply to admin
         Base::mint(e, &admin, initial_supply);
 
+        // Mint every initial allocation, within the cap
+        for (recipient, amount) in allocations.iter() {
+            check_cap(e, amount);
+            Base::mint(e, &recipient, amount);
+        }
+
         // Note: manager parameter included for consistency with other token types
         let _ = manager; // Silence unused warning
     }
@@ -0,0 +1,398 @@
+//! Fungible Composable Example Contract.
+
+//! This contract combines the capped, pausable, allowlist, blocklist, burnable
+//! and mintable extensions into a single SEP-41-compliant fungible token. The
+//! feature set is selected once at deployment through a bitmask, so an issuer
+//! can deploy, for instance, a token that is capped, pausable and blocklisted
+//! at the same time.
+//!
+//! Allowlist and blocklist modes are mutually exclusive: a token either only
+//! lets approved accounts transact, or lets everyone transact except blocked
+//! accounts.
+//!
+//! Holders who registered an ed25519 public key can also approve spenders
+//! off-chain: anyone can submit the signed approval with `permit`.
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short,
+    xdr::ToXdr, Address, BytesN, Env, String, Vec,
+};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_contract_utils::pausable::{self as pausable, Pausable};
+use stellar_macros::{default_impl, only_admin, only_role};
+use stellar_tokens::fungible::{
+    allowlist::AllowList,
+    blocklist::BlockList,
+    burnable::FungibleBurnable,
+    capped::{check_cap, query_cap, set_cap},
+    emit_approve, Base, FungibleToken, FungibleTokenError,
+};
+
+/// Enforces a maximum total supply.
+pub const FEATURE_CAPPED: u32 = 1 << 0;
+/// Adds an emergency stop for transfers, mints and burns.
+pub const FEATURE_PAUSABLE: u32 = 1 << 1;
+/// Only allowed accounts can send, receive or approve tokens.
+pub const FEATURE_ALLOWLIST: u32 = 1 << 2;
+/// Blocked accounts cannot send, receive or approve tokens.
+pub const FEATURE_BLOCKLIST: u32 = 1 << 3;
+/// Holders can burn their own tokens.
+pub const FEATURE_BURNABLE: u32 = 1 << 4;
+/// The admin can mint new tokens after deployment.
+pub const FEATURE_MINTABLE: u32 = 1 << 5;
+
+pub const ALL_FEATURES: u32 = FEATURE_CAPPED
+    | FEATURE_PAUSABLE
+    | FEATURE_ALLOWLIST
+    | FEATURE_BLOCKLIST
+    | FEATURE_BURNABLE
+    | FEATURE_MINTABLE;
+
+#[contracttype]
+pub enum DataKey {
+    Features,
+}
+
+/// Parameters for the features enabled in the bitmask.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureConfig {
+    /// Maximum supply, required if and only if `FEATURE_CAPPED` is set.
+    pub cap: Option<i128>,
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    UnknownFeature = 1,
+    ConflictingFeatures = 2,
+    FeatureDisabled = 3,
+    MissingCap = 4,
+    UnexpectedCap = 5,
+    SignatureExpired = 6,
+}
+
+#[contractimpl]
+impl ExampleContract {
+    #[allow(clippy::too_many_arguments)]
+    pub fn __constructor(
+        e: &Env,
+        admin: Address,
+        manager: Address,
+        initial_supply: i128,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        features: u32,
+        config: FeatureConfig,
+        allocations: Vec<(Address, i128)>,
+    ) {
+        validate_features(e, features, &config);
+
+        Base::set_metadata(e, decimals, name, symbol);
+        e.storage().instance().set(&DataKey::Features, &features);
+
+        access_control::set_admin(e, &admin);
+
+        // create a role "manager" and grant it to `manager`
+        access_control::grant_role_no_auth(e, &admin, &manager, &symbol_short!("manager"));
+
+        if let Some(cap) = config.cap {
+            set_cap(e, cap);
+            check_cap(e, initial_supply);
+        }
+
+        // Allow the admin to transfer tokens
+        if features & FEATURE_ALLOWLIST != 0 {
+            AllowList::allow_user(e, &admin);
+        }
+
+        // Mint initial supply to the admin
+        Base::mint(e, &admin, initial_supply);
+
+        // Mint every initial allocation, allowing recipients and staying
+        // within the cap when those features are enabled
+        for (recipient, amount) in allocations.iter() {
+            if features & FEATURE_ALLOWLIST != 0 && !AllowList::allowed(e, &recipient) {
+                AllowList::allow_user(e, &recipient);
+            }
+            if config.cap.is_some() {
+                check_cap(e, amount);
+            }
+            Base::mint(e, &recipient, amount);
+        }
+    }
+
+    /// Returns the feature bitmask selected at deployment.
+    pub fn features(e: &Env) -> u32 {
+        get_features(e)
+    }
+
+    /// Returns the maximum supply, or `None` if the token is not capped.
+    pub fn cap(e: &Env) -> Option<i128> {
+        if has_feature(e, FEATURE_CAPPED) {
+            Some(query_cap(e))
+        } else {
+            None
+        }
+    }
+
+    #[only_admin]
+    pub fn mint(e: &Env, account: Address, amount: i128) {
+        require_feature(e, FEATURE_MINTABLE);
+        when_not_paused(e);
+        check_account(e, &account);
+        if has_feature(e, FEATURE_CAPPED) {
+            check_cap(e, amount);
+        }
+        Base::mint(e, &account, amount);
+    }
+
+    /// Approves `spender` on behalf of `owner` using an ed25519 signature
+    /// over `("permit", token, owner, spender, amount, live_until_ledger,
+    /// nonce, deadline)`, serialized as XDR, made with the key registered by
+    /// `owner`. The signature cannot be used after ledger `deadline`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit(
+        e: &Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        live_until_ledger: u32,
+        nonce: u64,
+        deadline: u32,
+        signature: BytesN<64>,
+    ) {
+        if e.ledger().sequence() > deadline {
+            panic_with_error!(e, ExampleContractError::SignatureExpired);
+        }
+
+        let payload = (
+            symbol_short!("permit"),
+            e.current_contract_address(),
+            owner.clone(),
+            spender.clone(),
+            amount,
+            live_until_ledger,
+            nonce,
+            deadline,
+        );
+        signatures::verify_signature(e, &owner, nonce, payload.to_xdr(e), &signature);
+
+        // Same restrictions as `approve`
+        check_account(e, &owner);
+        if has_feature(e, FEATURE_BLOCKLIST) && BlockList::blocked(e, &spender) {
+            panic_with_error!(e, FungibleTokenError::UserBlocked);
+        }
+
+        Base::set_allowance(e, &owner, &spender, amount, live_until_ledger);
+        emit_approve(e, &owner, &spender, amount, live_until_ledger);
+    }
+
+    /// Registers the ed25519 public key `account` signs permits with.
+    /// Registering again replaces the previous key.
+    pub fn register_public_key(e: &Env, account: Address, public_key: BytesN<32>) {
+        account.require_auth();
+        signatures::set_public_key(e, &account, &public_key);
+    }
+
+    pub fn public_key(e: &Env, account: Address) -> Option<BytesN<32>> {
+        signatures::public_key(e, &account)
+    }
+
+    /// Returns the nonce the next permit from `account` must use.
+    pub fn nonce(e: &Env, account: Address) -> u64 {
+        signatures::nonce(e, &account)
+    }
+
+    /// Returns whether `account` may transact. Always `true` when the
+    /// allowlist is disabled.
+    pub fn allowed(e: &Env, account: Address) -> bool {
+        !has_feature(e, FEATURE_ALLOWLIST) || AllowList::allowed(e, &account)
+    }
+
+    #[only_role(operator, "manager")]
+    pub fn allow_user(e: &Env, user: Address, operator: Address) {
+        require_feature(e, FEATURE_ALLOWLIST);
+        AllowList::allow_user(e, &user)
+    }
+
+    #[only_role(operator, "manager")]
+    pub fn disallow_user(e: &Env, user: Address, operator: Address) {
+        require_feature(e, FEATURE_ALLOWLIST);
+        AllowList::disallow_user(e, &user)
+    }
+
+    /// Returns whether `account` is blocked. Always `false` when the blocklist
+    /// is disabled.
+    pub fn blocked(e: &Env, account: Address) -> bool {
+        has_feature(e, FEATURE_BLOCKLIST) && BlockList::blocked(e, &account)
+    }
+
+    #[only_role(operator, "manager")]
+    pub fn block_user(e: &Env, user: Address, operator: Address) {
+        require_feature(e, FEATURE_BLOCKLIST);
+        BlockList::block_user(e, &user)
+    }
+
+    #[only_role(operator, "manager")]
+    pub fn unblock_user(e: &Env, user: Address, operator: Address) {
+        require_feature(e, FEATURE_BLOCKLIST);
+        BlockList::unblock_user(e, &user)
+    }
+}
+
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+
+    fn total_supply(e: &Env) -> i128 {
+        Self::ContractType::total_supply(e)
+    }
+
+    fn balance(e: &Env, account: Address) -> i128 {
+        Self::ContractType::balance(e, &account)
+    }
+
+    fn allowance(e: &Env, owner: Address, spender: Address) -> i128 {
+        Self::ContractType::allowance(e, &owner, &spender)
+    }
+
+    fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        when_not_paused(e);
+        let features = get_features(e);
+        if features & FEATURE_ALLOWLIST != 0 {
+            AllowList::transfer(e, &from, &to, amount);
+        } else if features & FEATURE_BLOCKLIST != 0 {
+            BlockList::transfer(e, &from, &to, amount);
+        } else {
+            Base::transfer(e, &from, &to, amount);
+        }
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        when_not_paused(e);
+        let features = get_features(e);
+        if features & FEATURE_ALLOWLIST != 0 {
+            AllowList::transfer_from(e, &spender, &from, &to, amount);
+        } else if features & FEATURE_BLOCKLIST != 0 {
+            BlockList::transfer_from(e, &spender, &from, &to, amount);
+        } else {
+            Base::transfer_from(e, &spender, &from, &to, amount);
+        }
+    }
+
+    fn approve(e: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
+        let features = get_features(e);
+        if features & FEATURE_ALLOWLIST != 0 {
+            AllowList::approve(e, &owner, &spender, amount, live_until_ledger);
+        } else if features & FEATURE_BLOCKLIST != 0 {
+            BlockList::approve(e, &owner, &spender, amount, live_until_ledger);
+        } else {
+            Base::approve(e, &owner, &spender, amount, live_until_ledger);
+        }
+    }
+
+    fn decimals(e: &Env) -> u32 {
+        Self::ContractType::decimals(e)
+    }
+
+    fn name(e: &Env) -> String {
+        Self::ContractType::name(e)
+    }
+
+    fn symbol(e: &Env) -> String {
+        Self::ContractType::symbol(e)
+    }
+}
+
+#[contractimpl]
+impl FungibleBurnable for ExampleContract {
+    fn burn(e: &Env, from: Address, amount: i128) {
+        require_feature(e, FEATURE_BURNABLE);
+        when_not_paused(e);
+        check_account(e, &from);
+        Base::burn(e, &from, amount)
+    }
+
+    fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+        require_feature(e, FEATURE_BURNABLE);
+        when_not_paused(e);
+        check_account(e, &from);
+        Base::burn_from(e, &spender, &from, amount)
+    }
+}
+
+#[contractimpl]
+impl Pausable for ExampleContract {
+    fn paused(e: &Env) -> bool {
+        pausable::paused(e)
+    }
+
+    #[only_role(caller, "manager")]
+    fn pause(e: &Env, caller: Address) {
+        require_feature(e, FEATURE_PAUSABLE);
+        pausable::pause(e);
+    }
+
+    #[only_role(caller, "manager")]
+    fn unpause(e: &Env, caller: Address) {
+        require_feature(e, FEATURE_PAUSABLE);
+        pausable::unpause(e);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl AccessControl for ExampleContract {}
+
+fn validate_features(e: &Env, features: u32, config: &FeatureConfig) {
+    if features & !ALL_FEATURES != 0 {
+        panic_with_error!(e, ExampleContractError::UnknownFeature);
+    }
+    if features & FEATURE_ALLOWLIST != 0 && features & FEATURE_BLOCKLIST != 0 {
+        panic_with_error!(e, ExampleContractError::ConflictingFeatures);
+    }
+    let capped = features & FEATURE_CAPPED != 0;
+    if capped && config.cap.is_none() {
+        panic_with_error!(e, ExampleContractError::MissingCap);
+    }
+    if !capped && config.cap.is_some() {
+        panic_with_error!(e, ExampleContractError::UnexpectedCap);
+    }
+}
+
+fn get_features(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Features).unwrap_or(0)
+}
+
+fn has_feature(e: &Env, feature: u32) -> bool {
+    get_features(e) & feature != 0
+}
+
+fn require_feature(e: &Env, feature: u32) {
+    if !has_feature(e, feature) {
+        panic_with_error!(e, ExampleContractError::FeatureDisabled);
+    }
+}
+
+fn when_not_paused(e: &Env) {
+    if has_feature(e, FEATURE_PAUSABLE) {
+        pausable::when_not_paused(e);
+    }
+}
+
+// Rejects accounts that the enabled allowlist or blocklist mode excludes.
+fn check_account(e: &Env, account: &Address) {
+    let features = get_features(e);
+    if features & FEATURE_ALLOWLIST != 0 && !AllowList::allowed(e, account) {
+        panic_with_error!(e, FungibleTokenError::UserNotAllowed);
+    }
+    if features & FEATURE_BLOCKLIST != 0 && BlockList::blocked(e, account) {
+        panic_with_error!(e, FungibleTokenError::UserBlocked);
+    }
+}
@@ -0,0 +1,439 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    xdr::ToXdr,
+    Address, BytesN, Env, String, Vec,
+};
+
+use crate::contract::{
+    ExampleContract, ExampleContractClient, FeatureConfig, FEATURE_ALLOWLIST, FEATURE_BLOCKLIST,
+    FEATURE_BURNABLE, FEATURE_CAPPED, FEATURE_MINTABLE, FEATURE_PAUSABLE,
+};
+
+fn create_client<'a>(
+    e: &Env,
+    admin: &Address,
+    manager: &Address,
+    initial_supply: i128,
+    features: u32,
+    cap: Option<i128>,
+) -> ExampleContractClient<'a> {
+    create_client_with_allocations(e, admin, manager, initial_supply, features, cap, Vec::new(e))
+}
+
+fn create_client_with_allocations<'a>(
+    e: &Env,
+    admin: &Address,
+    manager: &Address,
+    initial_supply: i128,
+    features: u32,
+    cap: Option<i128>,
+    allocations: Vec<(Address, i128)>,
+) -> ExampleContractClient<'a> {
+    let address = e.register(
+        ExampleContract,
+        (
+            admin,
+            manager,
+            initial_supply,
+            String::from_str(e, "Composable Token"),
+            String::from_str(e, "CMP"),
+            7u32,
+            features,
+            FeatureConfig { cap },
+            allocations,
+        ),
+    );
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn plain_token_works() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, 0, None);
+
+    e.mock_all_auths();
+
+    assert_eq!(client.features(), 0);
+    assert_eq!(client.cap(), None);
+    assert!(client.allowed(&user));
+    assert!(!client.blocked(&user));
+
+    client.transfer(&admin, &user, &100);
+    assert_eq!(client.balance(&admin), 900);
+    assert_eq!(client.balance(&user), 100);
+}
+
+#[test]
+fn capped_pausable_blocklist_combination_works() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let features = FEATURE_CAPPED | FEATURE_PAUSABLE | FEATURE_BLOCKLIST | FEATURE_MINTABLE;
+    let client = create_client(&e, &admin, &manager, 1000, features, Some(5000));
+
+    e.mock_all_auths();
+
+    assert_eq!(client.features(), features);
+    assert_eq!(client.cap(), Some(5000));
+
+    client.mint(&user, &4000);
+    assert_eq!(client.total_supply(), 5000);
+
+    client.block_user(&user, &manager);
+    assert!(client.blocked(&user));
+    client.unblock_user(&user, &manager);
+
+    client.pause(&manager);
+    assert!(client.paused());
+    client.unpause(&manager);
+
+    client.transfer(&user, &admin, &500);
+    assert_eq!(client.balance(&admin), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn mint_above_cap_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let features = FEATURE_CAPPED | FEATURE_MINTABLE;
+    let client = create_client(&e, &admin, &manager, 1000, features, Some(1500));
+
+    e.mock_all_auths();
+
+    client.mint(&admin, &501);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn mint_without_mintable_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_BURNABLE, None);
+
+    e.mock_all_auths();
+
+    client.mint(&admin, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1000)")]
+fn transfer_fails_when_paused() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_PAUSABLE, None);
+
+    e.mock_all_auths();
+
+    client.pause(&manager);
+    client.transfer(&admin, &user, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn pause_without_pausable_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_BURNABLE, None);
+
+    e.mock_all_auths();
+
+    client.pause(&manager);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn allowlist_mode_rejects_unknown_recipient() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_ALLOWLIST, None);
+
+    e.mock_all_auths();
+
+    assert!(client.allowed(&admin));
+    assert!(!client.allowed(&user));
+    client.transfer(&admin, &user, &100);
+}
+
+#[test]
+fn allowlist_mode_allows_approved_recipient() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_ALLOWLIST, None);
+
+    e.mock_all_auths();
+
+    client.allow_user(&user, &manager);
+    client.transfer(&admin, &user, &100);
+    assert_eq!(client.balance(&user), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn blocklist_mode_rejects_blocked_sender() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_BLOCKLIST, None);
+
+    e.mock_all_auths();
+
+    client.transfer(&admin, &user, &100);
+    client.block_user(&user, &manager);
+    client.transfer(&user, &admin, &100);
+}
+
+#[test]
+fn burn_works_when_burnable() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_BURNABLE, None);
+
+    e.mock_all_auths();
+
+    client.burn(&admin, &200);
+    assert_eq!(client.total_supply(), 800);
+    assert_eq!(client.balance(&admin), 800);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn burn_without_burnable_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, 0, None);
+
+    e.mock_all_auths();
+
+    client.burn(&admin, &200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn allowlist_with_blocklist_is_rejected() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    create_client(&e, &admin, &manager, 1000, FEATURE_ALLOWLIST | FEATURE_BLOCKLIST, None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn unknown_feature_is_rejected() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    create_client(&e, &admin, &manager, 1000, 1 << 10, None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn capped_without_cap_is_rejected() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    create_client(&e, &admin, &manager, 1000, FEATURE_CAPPED, None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn cap_without_capped_is_rejected() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    create_client(&e, &admin, &manager, 1000, FEATURE_MINTABLE, Some(5000));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign_permit(
+    e: &Env,
+    key: &SigningKey,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    nonce: u64,
+    deadline: u32,
+) -> BytesN<64> {
+    let payload = (
+        symbol_short!("permit"),
+        token.clone(),
+        owner.clone(),
+        spender.clone(),
+        amount,
+        live_until_ledger,
+        nonce,
+        deadline,
+    )
+        .to_xdr(e);
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn permit_sets_allowance_without_owner_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_BLOCKLIST, None);
+
+    e.mock_all_auths();
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+    assert_eq!(client.nonce(&admin), 0);
+
+    let signature = sign_permit(&e, &key, &client.address, &admin, &spender, 400, 100, 0, 10);
+
+    // The relayer submits the permit, no auth from the owner
+    e.set_auths(&[]);
+    client.permit(&admin, &spender, &400, &100, &0, &10, &signature);
+    assert_eq!(client.allowance(&admin, &spender), 400);
+    assert_eq!(client.nonce(&admin), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #81)")]
+fn permit_replay_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, 0, None);
+
+    e.mock_all_auths();
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let signature = sign_permit(&e, &key, &client.address, &admin, &spender, 400, 100, 0, 10);
+    client.permit(&admin, &spender, &400, &100, &0, &10, &signature);
+    client.permit(&admin, &spender, &400, &100, &0, &10, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto")]
+fn permit_with_wrong_key_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, 0, None);
+
+    e.mock_all_auths();
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let other_key = SigningKey::from_bytes(&[8u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let signature =
+        sign_permit(&e, &other_key, &client.address, &admin, &spender, 400, 100, 0, 10);
+    client.permit(&admin, &spender, &400, &100, &0, &10, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn permit_after_deadline_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, 0, None);
+
+    e.mock_all_auths();
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    e.ledger().with_mut(|li| li.sequence_number = 11);
+    let signature = sign_permit(&e, &key, &client.address, &admin, &spender, 400, 100, 0, 10);
+    client.permit(&admin, &spender, &400, &100, &0, &10, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn permit_respects_allowlist() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager, 1000, FEATURE_ALLOWLIST, None);
+
+    e.mock_all_auths();
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&user, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let signature = sign_permit(&e, &key, &client.address, &user, &spender, 400, 100, 0, 10);
+    client.permit(&user, &spender, &400, &100, &0, &10, &signature);
+}
+
+#[test]
+fn initial_allocations_are_minted_and_allowed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let team = Address::generate(&e);
+    let pool = Address::generate(&e);
+    let mut allocations = Vec::new(&e);
+    allocations.push_back((team.clone(), 300));
+    allocations.push_back((pool.clone(), 700));
+    let client = create_client_with_allocations(
+        &e,
+        &admin,
+        &manager,
+        1_000,
+        FEATURE_CAPPED | FEATURE_ALLOWLIST,
+        Some(2_000),
+        allocations,
+    );
+
+    assert_eq!(client.balance(&team), 300);
+    assert_eq!(client.balance(&pool), 700);
+    assert_eq!(client.total_supply(), 2_000);
+    assert!(client.allowed(&team));
+    assert!(client.allowed(&pool));
+}
+
+#[test]
+#[should_panic]
+fn initial_allocations_above_cap_fail() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let mut allocations = Vec::new(&e);
+    allocations.push_back((Address::generate(&e), 1_001));
+    create_client_with_allocations(
+        &e,
+        &admin,
+        &manager,
+        1_000,
+        FEATURE_CAPPED,
+        Some(2_000),
+        allocations,
+    );
+}
@@ -0,0 +1,264 @@
+//! Fungible Fee-on-Transfer Example Contract.
+
+//! This contract showcases a SEP-41-compliant fungible token that skims a
+//! basis-point fee from every transfer and sends it to a treasury. For
+//! instance, with a fee of 100 bps, a transfer of 1000 tokens delivers 990
+//! tokens to the recipient and 10 tokens to the treasury.
+//!
+//! Transfers from or to an exempt account are not taxed. The treasury is
+//! always exempt, and the manager can exempt further accounts such as
+//! exchanges or liquidity pools.
+//!
+//! The fee rate can be changed by the manager at any time, but never above
+//! the maximum rate fixed at deployment.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error,
+    symbol_short, Address, Env, String, I256,
+};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_macros::{default_impl, only_admin, only_role};
+use stellar_tokens::fungible::{burnable::FungibleBurnable, emit_transfer, Base, FungibleToken};
+
+/// Denominator for fee rates: 10_000 bps = 100%.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contracttype]
+pub enum DataKey {
+    Treasury,
+    FeeBps,
+    MaxFeeBps,
+    Exempt(Address),
+}
+
+#[contractevent]
+pub struct FeeChargedEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub treasury: Address,
+    pub fee: i128,
+}
+
+#[contractevent]
+pub struct FeeRateUpdatedEvent {
+    pub fee_bps: u32,
+}
+
+#[contractevent]
+pub struct TreasuryUpdatedEvent {
+    pub treasury: Address,
+}
+
+#[contractevent]
+pub struct ExemptionUpdatedEvent {
+    #[topic]
+    pub account: Address,
+    pub exempt: bool,
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    FeeTooHigh = 1,
+    InvalidMaxFee = 2,
+}
+
+#[contractimpl]
+impl ExampleContract {
+    #[allow(clippy::too_many_arguments)]
+    pub fn __constructor(
+        e: &Env,
+        admin: Address,
+        manager: Address,
+        initial_supply: i128,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        treasury: Address,
+        fee_bps: u32,
+        max_fee_bps: u32,
+    ) {
+        if max_fee_bps > BPS_DENOMINATOR {
+            panic_with_error!(e, ExampleContractError::InvalidMaxFee);
+        }
+        if fee_bps > max_fee_bps {
+            panic_with_error!(e, ExampleContractError::FeeTooHigh);
+        }
+
+        Base::set_metadata(e, decimals, name, symbol);
+
+        access_control::set_admin(e, &admin);
+
+        // create a role "manager" and grant it to `manager`
+        access_control::grant_role_no_auth(e, &admin, &manager, &symbol_short!("manager"));
+
+        e.storage().instance().set(&DataKey::Treasury, &treasury);
+        e.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+        e.storage().instance().set(&DataKey::MaxFeeBps, &max_fee_bps);
+
+        // Mint initial supply to the admin
+        Base::mint(e, &admin, initial_supply);
+    }
+
+    #[only_admin]
+    pub fn mint(e: &Env, account: Address, amount: i128) {
+        Base::mint(e, &account, amount);
+    }
+
+    /// Returns the current fee rate in basis points.
+    pub fn fee_bps(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+    }
+
+    /// Returns the maximum fee rate in basis points, fixed at deployment.
+    pub fn max_fee_bps(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::MaxFeeBps).unwrap_or(0)
+    }
+
+    pub fn treasury(e: &Env) -> Address {
+        get_treasury(e)
+    }
+
+    /// Returns whether transfers from or to `account` are exempt from fees.
+    pub fn is_exempt(e: &Env, account: Address) -> bool {
+        is_exempt(e, &account)
+    }
+
+    /// Returns the fee charged on a non-exempt transfer of `amount`.
+    pub fn fee_for(e: &Env, amount: i128) -> i128 {
+        compute_fee(e, amount)
+    }
+
+    #[only_role(operator, "manager")]
+    pub fn set_fee_bps(e: &Env, fee_bps: u32, operator: Address) {
+        if fee_bps > Self::max_fee_bps(e) {
+            panic_with_error!(e, ExampleContractError::FeeTooHigh);
+        }
+        e.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+        FeeRateUpdatedEvent { fee_bps }.publish(e);
+    }
+
+    #[only_admin]
+    pub fn set_treasury(e: &Env, treasury: Address) {
+        e.storage().instance().set(&DataKey::Treasury, &treasury);
+        TreasuryUpdatedEvent { treasury }.publish(e);
+    }
+
+    #[only_role(operator, "manager")]
+    pub fn set_exempt(e: &Env, account: Address, exempt: bool, operator: Address) {
+        let key = DataKey::Exempt(account.clone());
+        if exempt {
+            e.storage().persistent().set(&key, &true);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+        ExemptionUpdatedEvent { account, exempt }.publish(e);
+    }
+}
+
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+
+    fn total_supply(e: &Env) -> i128 {
+        Self::ContractType::total_supply(e)
+    }
+
+    fn balance(e: &Env, account: Address) -> i128 {
+        Self::ContractType::balance(e, &account)
+    }
+
+    fn allowance(e: &Env, owner: Address, spender: Address) -> i128 {
+        Self::ContractType::allowance(e, &owner, &spender)
+    }
+
+    fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        transfer_with_fee(e, &from, &to, amount);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        // The allowance covers the gross amount, fee included
+        Base::spend_allowance(e, &from, &spender, amount);
+        transfer_with_fee(e, &from, &to, amount);
+    }
+
+    fn approve(e: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
+        Self::ContractType::approve(e, &owner, &spender, amount, live_until_ledger);
+    }
+
+    fn decimals(e: &Env) -> u32 {
+        Self::ContractType::decimals(e)
+    }
+
+    fn name(e: &Env) -> String {
+        Self::ContractType::name(e)
+    }
+
+    fn symbol(e: &Env) -> String {
+        Self::ContractType::symbol(e)
+    }
+}
+
+#[contractimpl]
+impl FungibleBurnable for ExampleContract {
+    fn burn(e: &Env, from: Address, amount: i128) {
+        Self::ContractType::burn(e, &from, amount)
+    }
+
+    fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+        Self::ContractType::burn_from(e, &spender, &from, amount)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl AccessControl for ExampleContract {}
+
+// Moves `amount` out of `from`, delivering it net of fees to `to` and the fee
+// to the treasury. Authorization must already have been checked. Balances only
+// move between accounts, so the total supply is unchanged.
+fn transfer_with_fee(e: &Env, from: &Address, to: &Address, amount: i128) {
+    let treasury = get_treasury(e);
+    let fee = if is_exempt(e, from) || is_exempt(e, to) {
+        0
+    } else {
+        compute_fee(e, amount)
+    };
+
+    let net = amount - fee;
+    Base::update(e, Some(from), Some(to), net);
+    emit_transfer(e, from, to, net);
+
+    if fee > 0 {
+        Base::update(e, Some(from), Some(&treasury), fee);
+        emit_transfer(e, from, &treasury, fee);
+        FeeChargedEvent { from: from.clone(), to: to.clone(), treasury, fee }.publish(e);
+    }
+}
+
+// Rounds down, so tiny transfers may be fee-free.
+fn compute_fee(e: &Env, amount: i128) -> i128 {
+    let fee_bps: u32 = e.storage().instance().get(&DataKey::FeeBps).unwrap_or(0);
+    // The product can exceed i128 for large amounts, the fee cannot
+    I256::from_i128(e, amount)
+        .mul(&I256::from_i128(e, fee_bps as i128))
+        .div(&I256::from_i128(e, BPS_DENOMINATOR as i128))
+        .to_i128()
+        .expect("fee is at most the amount")
+}
+
+fn is_exempt(e: &Env, account: &Address) -> bool {
+    *account == get_treasury(e) || e.storage().persistent().has(&DataKey::Exempt(account.clone()))
+}
+
+fn get_treasury(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Treasury).expect("treasury should be set")
+}
@@ -0,0 +1,214 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::contract::{ExampleContract, ExampleContractClient};
+
+struct Setup<'a> {
+    client: ExampleContractClient<'a>,
+    admin: Address,
+    manager: Address,
+    treasury: Address,
+}
+
+fn create_client<'a>(e: &Env, initial_supply: i128, fee_bps: u32, max_fee_bps: u32) -> Setup<'a> {
+    let admin = Address::generate(e);
+    let manager = Address::generate(e);
+    let treasury = Address::generate(e);
+    let address = e.register(
+        ExampleContract,
+        (
+            &admin,
+            &manager,
+            initial_supply,
+            String::from_str(e, "Fee Token"),
+            String::from_str(e, "FEE"),
+            7u32,
+            &treasury,
+            fee_bps,
+            max_fee_bps,
+        ),
+    );
+    Setup { client: ExampleContractClient::new(e, &address), admin, manager, treasury }
+}
+
+#[test]
+fn transfer_charges_fee_to_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+    let user = Address::generate(&e);
+
+    s.client.transfer(&s.admin, &user, &1000);
+
+    assert_eq!(s.client.balance(&s.admin), 9000);
+    assert_eq!(s.client.balance(&user), 990);
+    assert_eq!(s.client.balance(&s.treasury), 10);
+    assert_eq!(s.client.total_supply(), 10_000);
+}
+
+#[test]
+fn transfer_from_charges_fee_on_gross_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 250, 500);
+    let spender = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    s.client.approve(&s.admin, &spender, &1000, &1000);
+    s.client.transfer_from(&spender, &s.admin, &user, &1000);
+
+    assert_eq!(s.client.allowance(&s.admin, &spender), 0);
+    assert_eq!(s.client.balance(&user), 975);
+    assert_eq!(s.client.balance(&s.treasury), 25);
+    assert_eq!(s.client.total_supply(), 10_000);
+}
+
+#[test]
+fn fee_rounds_down() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+    let user = Address::generate(&e);
+
+    assert_eq!(s.client.fee_for(&99), 0);
+    assert_eq!(s.client.fee_for(&199), 1);
+
+    s.client.transfer(&s.admin, &user, &199);
+    assert_eq!(s.client.balance(&user), 198);
+    assert_eq!(s.client.balance(&s.treasury), 1);
+}
+
+#[test]
+fn max_amount_transfers_without_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, i128::MAX, 100, 500);
+    let user = Address::generate(&e);
+
+    s.client.transfer(&s.admin, &user, &i128::MAX);
+
+    assert_eq!(s.client.balance(&s.treasury), i128::MAX / 100);
+    assert_eq!(s.client.balance(&user), i128::MAX - i128::MAX / 100);
+    assert_eq!(s.client.total_supply(), i128::MAX);
+}
+
+#[test]
+fn accounting_stays_exact_across_many_transfers() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 1_000_000, 137, 1000);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let exchange = Address::generate(&e);
+    s.client.set_exempt(&exchange, &true, &s.manager);
+
+    s.client.transfer(&s.admin, &alice, &333_333);
+    for amount in [1, 7, 73, 731, 7_313, 73_137] {
+        s.client.transfer(&alice, &bob, &amount);
+        s.client.transfer(&bob, &exchange, &(amount / 2));
+    }
+    s.client.transfer(&exchange, &alice, &1_000);
+    s.client.burn(&alice, &500);
+
+    let holders = [&s.admin, &alice, &bob, &exchange, &s.treasury];
+    let sum: i128 = holders.iter().map(|a| s.client.balance(a)).sum();
+    assert_eq!(s.client.total_supply(), 999_500);
+    assert_eq!(sum, s.client.total_supply());
+}
+
+#[test]
+fn exempt_accounts_pay_no_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+    let exchange = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    s.client.set_exempt(&exchange, &true, &s.manager);
+    assert!(s.client.is_exempt(&exchange));
+    assert!(s.client.is_exempt(&s.treasury));
+
+    // Exempt recipient
+    s.client.transfer(&s.admin, &exchange, &1000);
+    assert_eq!(s.client.balance(&exchange), 1000);
+    // Exempt sender
+    s.client.transfer(&exchange, &user, &500);
+    assert_eq!(s.client.balance(&user), 500);
+    assert_eq!(s.client.balance(&s.treasury), 0);
+
+    s.client.set_exempt(&exchange, &false, &s.manager);
+    s.client.transfer(&exchange, &user, &500);
+    assert_eq!(s.client.balance(&user), 995);
+    assert_eq!(s.client.balance(&s.treasury), 5);
+}
+
+#[test]
+fn manager_updates_fee_within_max() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+    let user = Address::generate(&e);
+
+    s.client.set_fee_bps(&500, &s.manager);
+    assert_eq!(s.client.fee_bps(), 500);
+    assert_eq!(s.client.max_fee_bps(), 500);
+
+    s.client.transfer(&s.admin, &user, &1000);
+    assert_eq!(s.client.balance(&s.treasury), 50);
+
+    s.client.set_fee_bps(&0, &s.manager);
+    s.client.transfer(&s.admin, &user, &1000);
+    assert_eq!(s.client.balance(&user), 1950);
+}
+
+#[test]
+fn admin_updates_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+    let new_treasury = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    s.client.set_treasury(&new_treasury);
+    assert_eq!(s.client.treasury(), new_treasury);
+
+    s.client.transfer(&s.admin, &user, &1000);
+    assert_eq!(s.client.balance(&new_treasury), 10);
+    assert_eq!(s.client.balance(&s.treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn fee_above_max_is_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+
+    s.client.set_fee_bps(&501, &s.manager);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn set_fee_requires_manager() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = create_client(&e, 10_000, 100, 500);
+    let user = Address::generate(&e);
+
+    s.client.set_fee_bps(&200, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn initial_fee_above_max_is_rejected() {
+    let e = Env::default();
+    create_client(&e, 10_000, 600, 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn max_fee_above_100_percent_is_rejected() {
+    let e = Env::default();
+    create_client(&e, 10_000, 100, 10_001);
+}
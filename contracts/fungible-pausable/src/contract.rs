@@ -11,7 +11,7 @@
 
 use soroban_sdk::{
     contract, contracterror, contractimpl, panic_with_error, symbol_short, Address, Env, String,
-    Symbol,
+    Symbol, Vec,
 };
 use stellar_contract_utils::pausable::{self as pausable, Pausable};
 use stellar_macros::when_not_paused;
@@ -31,6 +31,7 @@ pub enum ExampleContractError {
 
 #[contractimpl]
 impl ExampleContract {
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         e: &Env,
         admin: Address,
@@ -39,9 +40,13 @@ impl ExampleContract {
         name: String,
         symbol: String,
         decimals: u32,
+        allocations: Vec<(Address, i128)>,
     ) {
         Base::set_metadata(e, decimals, name, symbol);
         Base::mint(e, &admin, initial_supply);
+        for (recipient, amount) in allocations.iter() {
+            Base::mint(e, &recipient, amount);
+        }
         e.storage().instance().set(&OWNER, &admin);
         // Note: manager parameter included for consistency with other token types
         // This implementation uses admin as the owner
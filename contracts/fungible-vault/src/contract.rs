@@ -1,29 +1,602 @@
 //! Tokenized Vault Example Contract.
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
-use stellar_macros::default_impl;
+//! Risk limits are managed by two roles:
+//!
+//! - the admin can pause deposits and withdrawals independently;
+//! - the curator sets a global cap on total assets (TVL) and caps on the
+//!   assets held by each receiver, with a default cap and per-receiver
+//!   overrides.
+//!
+//! `max_deposit`, `max_mint`, `max_withdraw` and `max_redeem` reflect these
+//! limits, so integrators can check them before calling.
+//!
+//! The vault also charges two fees, both paid by minting new shares to the
+//! fee recipient:
+//!
+//! - a management fee, an annual rate on total assets accruing every second;
+//! - a performance fee, a rate on the gain in share price above the
+//!   high-water mark, i.e. the highest price on which fees were charged.
+//!
+//! Fees accrue on every deposit, mint, withdrawal and redemption, or when
+//! anyone calls `accrue_fees`. The `preview_*` and conversion functions
+//! include pending fees, so they match what the next operation would do.
+//! The curator can change the fee rates, never above the maximum rate fixed
+//! at deployment.
+//!
+//! Idle assets can be put to work in strategies (see `strategy.rs`). The
+//! curator registers strategies with a cap on the assets each may hold, and
+//! allocates and deallocates assets between the idle balance and the
+//! strategies. `total_assets` is the idle balance plus the value reported by
+//! every strategy, while withdrawals are paid from the idle balance only.
+//!
+//! When the idle balance is too low, owners can queue redemptions instead
+//! (ERC-7540 style): `request_redeem` escrows the shares in the vault, the
+//! curator fulfils queued requests in FIFO order at the share price of the
+//! fulfilment, and owners `claim` the assets set aside for them. Escrowed
+//! shares keep their exposure to the vault until fulfilled, and assets set
+//! aside for claims no longer count towards `total_assets`.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error,
+    symbol_short, token, Address, Env, String, Vec, I256,
+};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_macros::{default_impl, only_admin, only_role};
 use stellar_tokens::{
     fungible::{Base, FungibleToken},
-    vault::{FungibleVault, Vault},
+    vault::{FungibleVault, Vault, VaultTokenError},
 };
 
+use crate::strategy::StrategyClient;
+
+/// Denominator for fee rates: 10_000 bps = 100%.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// The management fee rate is charged over this period.
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// Fixed-point scale of the share price tracked by the high-water mark.
+pub const PRICE_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Bounds the strategies queried by every `total_assets` call.
+pub const MAX_STRATEGIES: u32 = 10;
+
+#[contracttype]
+pub enum DataKey {
+    TvlCap,
+    DefaultReceiverCap,
+    ReceiverCap(Address),
+    DepositsPaused,
+    WithdrawalsPaused,
+    FeeRecipient,
+    ManagementFeeBps,
+    PerformanceFeeBps,
+    MaxFeeBps,
+    LastAccrual,
+    HighWaterMark,
+    Strategies,
+    StrategyCap(Address),
+    RedeemRequest(u64),
+    QueueHead,
+    QueueTail,
+    PendingShares(Address),
+    ClaimableAssets(Address),
+    TotalClaimable,
+}
+
+/// A queued redemption, waiting to be fulfilled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedeemRequest {
+    pub owner: Address,
+    pub shares: i128,
+}
+
+#[contractevent]
+pub struct TvlCapUpdatedEvent {
+    pub cap: Option<i128>,
+}
+
+#[contractevent]
+pub struct ReceiverCapUpdatedEvent {
+    /// `None` for the default cap applied to every receiver without override.
+    pub receiver: Option<Address>,
+    pub cap: Option<i128>,
+}
+
+#[contractevent]
+pub struct PauseUpdatedEvent {
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+}
+
+#[contractevent]
+pub struct FeesAccruedEvent {
+    #[topic]
+    pub recipient: Address,
+    pub shares: i128,
+}
+
+#[contractevent]
+pub struct FeeRatesUpdatedEvent {
+    pub management_fee_bps: u32,
+    pub performance_fee_bps: u32,
+}
+
+#[contractevent]
+pub struct FeeRecipientUpdatedEvent {
+    pub recipient: Address,
+}
+
+#[contractevent]
+pub struct DepositEvent {
+    #[topic]
+    pub operator: Address,
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub receiver: Address,
+    pub assets: i128,
+    pub shares: i128,
+}
+
+#[contractevent]
+pub struct WithdrawEvent {
+    #[topic]
+    pub operator: Address,
+    #[topic]
+    pub receiver: Address,
+    #[topic]
+    pub owner: Address,
+    pub assets: i128,
+    pub shares: i128,
+}
+
+#[contractevent]
+pub struct StrategyUpdatedEvent {
+    #[topic]
+    pub strategy: Address,
+    /// `None` once the strategy is removed.
+    pub cap: Option<i128>,
+}
+
+#[contractevent]
+pub struct AllocationEvent {
+    #[topic]
+    pub strategy: Address,
+    /// Positive when assets move to the strategy, negative when they return.
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct RedeemRequestedEvent {
+    #[topic]
+    pub owner: Address,
+    pub request_id: u64,
+    pub shares: i128,
+}
+
+#[contractevent]
+pub struct RedeemFulfilledEvent {
+    #[topic]
+    pub owner: Address,
+    pub request_id: u64,
+    pub shares: i128,
+    pub assets: i128,
+}
+
+#[contractevent]
+pub struct RedeemClaimedEvent {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub receiver: Address,
+    pub assets: i128,
+}
+
 #[contract]
 pub struct ExampleContract;
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    DepositsPaused = 1,
+    WithdrawalsPaused = 2,
+    ExceededDepositLimit = 3,
+    InvalidCap = 4,
+    FeeTooHigh = 5,
+    InvalidMaxFee = 6,
+    MathOverflow = 7,
+    StrategyAlreadyAdded = 8,
+    StrategyNotFound = 9,
+    TooManyStrategies = 10,
+    StrategyNotEmpty = 11,
+    ExceededStrategyCap = 12,
+    InsufficientIdleAssets = 13,
+    InvalidAmount = 14,
+    NothingToClaim = 15,
+}
+
 #[contractimpl]
 impl ExampleContract {
-    pub fn __constructor(e: &Env, asset: Address, decimals_offset: u32) {
+    #[allow(clippy::too_many_arguments)]
+    pub fn __constructor(
+        e: &Env,
+        asset: Address,
+        decimals_offset: u32,
+        name: String,
+        symbol: String,
+        admin: Address,
+        curator: Address,
+        fee_recipient: Address,
+        management_fee_bps: u32,
+        performance_fee_bps: u32,
+        max_fee_bps: u32,
+    ) {
+        if max_fee_bps > BPS_DENOMINATOR {
+            panic_with_error!(e, ExampleContractError::InvalidMaxFee);
+        }
+        if management_fee_bps > max_fee_bps || performance_fee_bps > max_fee_bps {
+            panic_with_error!(e, ExampleContractError::FeeTooHigh);
+        }
+
         // Asset and decimal offset should be configured once during initialization.
         Vault::set_asset(e, asset);
         Vault::set_decimals_offset(e, decimals_offset);
         // Vault overrides the decimals function by default.
         // Decimal offset must be set prior to metadata initialization.
-        Base::set_metadata(
-            e,
-            Self::decimals(e),
-            String::from_str(e, "Vault Token"),
-            String::from_str(e, "VLT"),
+        Base::set_metadata(e, Self::decimals(e), name, symbol);
+
+        access_control::set_admin(e, &admin);
+
+        // create a role "curator" and grant it to `curator`
+        access_control::grant_role_no_auth(e, &admin, &curator, &symbol_short!("curator"));
+
+        e.storage().instance().set(&DataKey::FeeRecipient, &fee_recipient);
+        e.storage().instance().set(&DataKey::ManagementFeeBps, &management_fee_bps);
+        e.storage().instance().set(&DataKey::PerformanceFeeBps, &performance_fee_bps);
+        e.storage().instance().set(&DataKey::MaxFeeBps, &max_fee_bps);
+        e.storage().instance().set(&DataKey::LastAccrual, &e.ledger().timestamp());
+        // The high-water mark starts at the initial share price.
+        e.storage().instance().set(&DataKey::HighWaterMark, &share_price(e, 0, 0));
+    }
+
+    /// Returns the cap on total assets, if any.
+    pub fn tvl_cap(e: &Env) -> Option<i128> {
+        e.storage().instance().get(&DataKey::TvlCap)
+    }
+
+    /// Returns the cap on assets held by `receiver`: its override if set,
+    /// otherwise the default cap.
+    pub fn receiver_cap(e: &Env, receiver: Address) -> Option<i128> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::ReceiverCap(receiver))
+            .or_else(|| e.storage().instance().get(&DataKey::DefaultReceiverCap))
+    }
+
+    pub fn deposits_paused(e: &Env) -> bool {
+        e.storage().instance().get(&DataKey::DepositsPaused).unwrap_or(false)
+    }
+
+    pub fn withdrawals_paused(e: &Env) -> bool {
+        e.storage().instance().get(&DataKey::WithdrawalsPaused).unwrap_or(false)
+    }
+
+    pub fn fee_recipient(e: &Env) -> Address {
+        e.storage().instance().get(&DataKey::FeeRecipient).unwrap()
+    }
+
+    /// Returns the annual management fee rate in basis points.
+    pub fn management_fee_bps(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::ManagementFeeBps).unwrap_or(0)
+    }
+
+    /// Returns the performance fee rate in basis points.
+    pub fn performance_fee_bps(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::PerformanceFeeBps).unwrap_or(0)
+    }
+
+    /// Returns the maximum fee rate in basis points, fixed at deployment.
+    pub fn max_fee_bps(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::MaxFeeBps).unwrap_or(0)
+    }
+
+    /// Returns the highest share price on which performance fees were
+    /// charged, scaled by `PRICE_SCALE`.
+    pub fn high_water_mark(e: &Env) -> i128 {
+        e.storage().instance().get(&DataKey::HighWaterMark).unwrap_or(0)
+    }
+
+    /// Returns the fee shares that `accrue_fees` would mint now.
+    pub fn pending_fee_shares(e: &Env) -> i128 {
+        pending_fees(e).0
+    }
+
+    /// Mints the pending management and performance fees as shares to the
+    /// fee recipient and returns the number of shares minted.
+    pub fn accrue_fees(e: &Env) -> i128 {
+        let (shares, high_water_mark) = pending_fees(e);
+        e.storage().instance().set(&DataKey::LastAccrual, &e.ledger().timestamp());
+        e.storage().instance().set(&DataKey::HighWaterMark, &high_water_mark);
+        if shares > 0 {
+            let recipient = Self::fee_recipient(e);
+            Base::mint(e, &recipient, shares);
+            FeesAccruedEvent { recipient, shares }.publish(e);
+        }
+        shares
+    }
+
+    /// Sets both fee rates, after charging the fees accrued at the old rates.
+    #[only_role(operator, "curator")]
+    pub fn set_fee_rates(
+        e: &Env,
+        management_fee_bps: u32,
+        performance_fee_bps: u32,
+        operator: Address,
+    ) {
+        let max_fee_bps = Self::max_fee_bps(e);
+        if management_fee_bps > max_fee_bps || performance_fee_bps > max_fee_bps {
+            panic_with_error!(e, ExampleContractError::FeeTooHigh);
+        }
+        Self::accrue_fees(e);
+        e.storage().instance().set(&DataKey::ManagementFeeBps, &management_fee_bps);
+        e.storage().instance().set(&DataKey::PerformanceFeeBps, &performance_fee_bps);
+        FeeRatesUpdatedEvent { management_fee_bps, performance_fee_bps }.publish(e);
+    }
+
+    /// Sets the fee recipient, after paying the fees accrued so far to the
+    /// previous one.
+    #[only_admin]
+    pub fn set_fee_recipient(e: &Env, recipient: Address) {
+        Self::accrue_fees(e);
+        e.storage().instance().set(&DataKey::FeeRecipient, &recipient);
+        FeeRecipientUpdatedEvent { recipient }.publish(e);
+    }
+
+    /// Returns the registered strategies.
+    pub fn strategies(e: &Env) -> Vec<Address> {
+        e.storage().instance().get(&DataKey::Strategies).unwrap_or_else(|| Vec::new(e))
+    }
+
+    /// Returns the cap on the assets held by `strategy`.
+    pub fn strategy_cap(e: &Env, strategy: Address) -> i128 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::StrategyCap(strategy))
+            .unwrap_or_else(|| panic_with_error!(e, ExampleContractError::StrategyNotFound))
+    }
+
+    /// Returns the assets held by the vault itself and available for
+    /// withdrawals, i.e. excluding those set aside for claims.
+    pub fn idle_assets(e: &Env) -> i128 {
+        token::Client::new(e, &Vault::query_asset(e)).balance(&e.current_contract_address())
+            - Self::total_claimable_assets(e)
+    }
+
+    /// Returns the shares escrowed by `owner` in queued redemptions.
+    pub fn pending_redeem_shares(e: &Env, owner: Address) -> i128 {
+        e.storage().persistent().get(&DataKey::PendingShares(owner)).unwrap_or(0)
+    }
+
+    /// Returns the assets `owner` can claim from fulfilled redemptions.
+    pub fn claimable_redeem_assets(e: &Env, owner: Address) -> i128 {
+        e.storage().persistent().get(&DataKey::ClaimableAssets(owner)).unwrap_or(0)
+    }
+
+    /// Returns the assets set aside for all owners' claims.
+    pub fn total_claimable_assets(e: &Env) -> i128 {
+        e.storage().instance().get(&DataKey::TotalClaimable).unwrap_or(0)
+    }
+
+    /// Returns the queued request with id `request_id`, if not yet fulfilled.
+    pub fn redeem_request(e: &Env, request_id: u64) -> Option<RedeemRequest> {
+        e.storage().persistent().get(&DataKey::RedeemRequest(request_id))
+    }
+
+    /// Returns the number of queued requests waiting to be fulfilled.
+    pub fn queued_requests(e: &Env) -> u64 {
+        queue_tail(e) - queue_head(e)
+    }
+
+    /// Escrows `shares` of `owner` and queues their redemption. Returns the
+    /// id of the request.
+    pub fn request_redeem(e: &Env, shares: i128, owner: Address, operator: Address) -> u64 {
+        operator.require_auth();
+        when_withdrawals_not_paused(e);
+        check_amount(e, shares);
+        if operator != owner {
+            Base::spend_allowance(e, &owner, &operator, shares);
+        }
+        Base::update(e, Some(&owner), Some(&e.current_contract_address()), shares);
+
+        let request_id = queue_tail(e);
+        let request = RedeemRequest { owner: owner.clone(), shares };
+        e.storage().persistent().set(&DataKey::RedeemRequest(request_id), &request);
+        e.storage().instance().set(&DataKey::QueueTail, &(request_id + 1));
+        let pending = Self::pending_redeem_shares(e, owner.clone()) + shares;
+        e.storage().persistent().set(&DataKey::PendingShares(owner.clone()), &pending);
+
+        RedeemRequestedEvent { owner, request_id, shares }.publish(e);
+        request_id
+    }
+
+    /// Fulfils up to `max_requests` queued requests in FIFO order, stopping
+    /// at the first one the idle balance cannot cover. Returns the number of
+    /// requests fulfilled.
+    #[only_role(operator, "curator")]
+    pub fn fulfill_redemptions(e: &Env, max_requests: u32, operator: Address) -> u32 {
+        when_withdrawals_not_paused(e);
+        Self::accrue_fees(e);
+
+        let mut head = queue_head(e);
+        let tail = queue_tail(e);
+        let mut fulfilled = 0;
+        while fulfilled < max_requests && head < tail {
+            let request = Self::redeem_request(e, head).unwrap();
+            let assets = Self::preview_redeem(e, request.shares);
+            if assets > Self::idle_assets(e) {
+                break;
+            }
+
+            Base::update(e, Some(&e.current_contract_address()), None, request.shares);
+            e.storage().persistent().remove(&DataKey::RedeemRequest(head));
+            let owner = request.owner.clone();
+            let pending = Self::pending_redeem_shares(e, owner.clone()) - request.shares;
+            set_or_remove_balance(e, &DataKey::PendingShares(owner.clone()), pending);
+            let claimable = Self::claimable_redeem_assets(e, owner.clone()) + assets;
+            e.storage().persistent().set(&DataKey::ClaimableAssets(owner.clone()), &claimable);
+            let total_claimable = Self::total_claimable_assets(e) + assets;
+            e.storage().instance().set(&DataKey::TotalClaimable, &total_claimable);
+
+            RedeemFulfilledEvent { owner, request_id: head, shares: request.shares, assets }
+                .publish(e);
+            head += 1;
+            fulfilled += 1;
+        }
+        e.storage().instance().set(&DataKey::QueueHead, &head);
+        fulfilled
+    }
+
+    /// Sends all assets claimable by `owner` to `receiver` and returns them.
+    pub fn claim(e: &Env, owner: Address, receiver: Address) -> i128 {
+        owner.require_auth();
+        let assets = Self::claimable_redeem_assets(e, owner.clone());
+        if assets == 0 {
+            panic_with_error!(e, ExampleContractError::NothingToClaim);
+        }
+        e.storage().persistent().remove(&DataKey::ClaimableAssets(owner.clone()));
+        let total_claimable = Self::total_claimable_assets(e) - assets;
+        e.storage().instance().set(&DataKey::TotalClaimable, &total_claimable);
+        token::Client::new(e, &Vault::query_asset(e)).transfer(
+            &e.current_contract_address(),
+            &receiver,
+            &assets,
         );
+        RedeemClaimedEvent { owner, receiver, assets }.publish(e);
+        assets
+    }
+
+    /// Registers `strategy` with a cap on the assets it may hold.
+    #[only_role(operator, "curator")]
+    pub fn add_strategy(e: &Env, strategy: Address, cap: i128, operator: Address) {
+        check_cap(e, Some(cap));
+        let mut strategies = Self::strategies(e);
+        if strategies.contains(&strategy) {
+            panic_with_error!(e, ExampleContractError::StrategyAlreadyAdded);
+        }
+        if strategies.len() >= MAX_STRATEGIES {
+            panic_with_error!(e, ExampleContractError::TooManyStrategies);
+        }
+        strategies.push_back(strategy.clone());
+        e.storage().instance().set(&DataKey::Strategies, &strategies);
+        e.storage().persistent().set(&DataKey::StrategyCap(strategy.clone()), &cap);
+        StrategyUpdatedEvent { strategy, cap: Some(cap) }.publish(e);
+    }
+
+    /// Unregisters `strategy`, which must hold no assets.
+    #[only_role(operator, "curator")]
+    pub fn remove_strategy(e: &Env, strategy: Address, operator: Address) {
+        let mut strategies = Self::strategies(e);
+        let index = strategies
+            .first_index_of(&strategy)
+            .unwrap_or_else(|| panic_with_error!(e, ExampleContractError::StrategyNotFound));
+        if StrategyClient::new(e, &strategy).total_value() != 0 {
+            panic_with_error!(e, ExampleContractError::StrategyNotEmpty);
+        }
+        strategies.remove(index);
+        e.storage().instance().set(&DataKey::Strategies, &strategies);
+        e.storage().persistent().remove(&DataKey::StrategyCap(strategy.clone()));
+        StrategyUpdatedEvent { strategy, cap: None }.publish(e);
+    }
+
+    /// Updates the cap of `strategy`. Assets above a lowered cap stay in the
+    /// strategy until deallocated.
+    #[only_role(operator, "curator")]
+    pub fn set_strategy_cap(e: &Env, strategy: Address, cap: i128, operator: Address) {
+        check_cap(e, Some(cap));
+        Self::strategy_cap(e, strategy.clone());
+        e.storage().persistent().set(&DataKey::StrategyCap(strategy.clone()), &cap);
+        StrategyUpdatedEvent { strategy, cap: Some(cap) }.publish(e);
+    }
+
+    /// Moves `amount` of idle assets to `strategy`.
+    #[only_role(operator, "curator")]
+    pub fn allocate(e: &Env, strategy: Address, amount: i128, operator: Address) {
+        check_amount(e, amount);
+        let cap = Self::strategy_cap(e, strategy.clone());
+        let client = StrategyClient::new(e, &strategy);
+        if client.total_value() + amount > cap {
+            panic_with_error!(e, ExampleContractError::ExceededStrategyCap);
+        }
+        if amount > Self::idle_assets(e) {
+            panic_with_error!(e, ExampleContractError::InsufficientIdleAssets);
+        }
+        token::Client::new(e, &Vault::query_asset(e)).transfer(
+            &e.current_contract_address(),
+            &strategy,
+            &amount,
+        );
+        client.deposit(&amount);
+        AllocationEvent { strategy, amount }.publish(e);
+    }
+
+    /// Moves `amount` of assets from `strategy` back to the idle balance.
+    #[only_role(operator, "curator")]
+    pub fn deallocate(e: &Env, strategy: Address, amount: i128, operator: Address) {
+        check_amount(e, amount);
+        Self::strategy_cap(e, strategy.clone());
+        StrategyClient::new(e, &strategy).withdraw(&amount);
+        AllocationEvent { strategy, amount: -amount }.publish(e);
+    }
+
+    /// Sets or removes (`None`) the cap on total assets.
+    #[only_role(operator, "curator")]
+    pub fn set_tvl_cap(e: &Env, cap: Option<i128>, operator: Address) {
+        check_cap(e, cap);
+        set_or_remove(e, &DataKey::TvlCap, cap);
+        TvlCapUpdatedEvent { cap }.publish(e);
+    }
+
+    /// Sets or removes (`None`) the cap applied to receivers without override.
+    #[only_role(operator, "curator")]
+    pub fn set_default_receiver_cap(e: &Env, cap: Option<i128>, operator: Address) {
+        check_cap(e, cap);
+        set_or_remove(e, &DataKey::DefaultReceiverCap, cap);
+        ReceiverCapUpdatedEvent { receiver: None, cap }.publish(e);
+    }
+
+    /// Sets or removes (`None`) the cap override for `receiver`.
+    #[only_role(operator, "curator")]
+    pub fn set_receiver_cap(e: &Env, receiver: Address, cap: Option<i128>, operator: Address) {
+        check_cap(e, cap);
+        let key = DataKey::ReceiverCap(receiver.clone());
+        match cap {
+            Some(cap) => e.storage().persistent().set(&key, &cap),
+            None => e.storage().persistent().remove(&key),
+        }
+        ReceiverCapUpdatedEvent { receiver: Some(receiver), cap }.publish(e);
+    }
+
+    #[only_admin]
+    pub fn set_deposits_paused(e: &Env, paused: bool) {
+        e.storage().instance().set(&DataKey::DepositsPaused, &paused);
+        PauseUpdatedEvent {
+            deposits_paused: paused,
+            withdrawals_paused: Self::withdrawals_paused(e),
+        }
+        .publish(e);
+    }
+
+    #[only_admin]
+    pub fn set_withdrawals_paused(e: &Env, paused: bool) {
+        e.storage().instance().set(&DataKey::WithdrawalsPaused, &paused);
+        PauseUpdatedEvent {
+            deposits_paused: Self::deposits_paused(e),
+            withdrawals_paused: paused,
+        }
+        .publish(e);
     }
 }
 
@@ -48,57 +621,97 @@ impl FungibleVault for ExampleContract {
     }
 
     fn total_assets(e: &Env) -> i128 {
-        Vault::total_assets(e)
+        Self::strategies(e)
+            .iter()
+            .fold(Self::idle_assets(e), |total, strategy| {
+                total + StrategyClient::new(e, &strategy).total_value()
+            })
     }
 
     fn convert_to_shares(e: &Env, assets: i128) -> i128 {
-        Vault::convert_to_shares(e, assets)
+        to_shares(e, assets, false)
     }
 
     fn convert_to_assets(e: &Env, shares: i128) -> i128 {
-        Vault::convert_to_assets(e, shares)
+        to_assets(e, shares, false)
     }
 
     fn max_deposit(e: &Env, receiver: Address) -> i128 {
-        Vault::max_deposit(e, receiver)
+        if Self::deposits_paused(e) {
+            return 0;
+        }
+
+        let mut limit = Vault::max_deposit(e, receiver.clone());
+        if let Some(cap) = Self::tvl_cap(e) {
+            limit = limit.min(cap - Self::total_assets(e));
+        }
+        if let Some(cap) = Self::receiver_cap(e, receiver.clone()) {
+            let position = to_assets(e, Base::balance(e, &receiver), false);
+            limit = limit.min(cap - position);
+        }
+        limit.max(0)
     }
 
     fn preview_deposit(e: &Env, assets: i128) -> i128 {
-        Vault::preview_deposit(e, assets)
+        to_shares(e, assets, false)
     }
 
     fn max_mint(e: &Env, receiver: Address) -> i128 {
-        Vault::max_mint(e, receiver)
+        let max_assets = Self::max_deposit(e, receiver.clone());
+        if max_assets == i128::MAX {
+            return Vault::max_mint(e, receiver);
+        }
+        to_shares(e, max_assets, false)
     }
 
     fn preview_mint(e: &Env, shares: i128) -> i128 {
-        Vault::preview_mint(e, shares)
+        to_assets(e, shares, true)
     }
 
     fn max_withdraw(e: &Env, owner: Address) -> i128 {
-        Vault::max_withdraw(e, owner)
+        if Self::withdrawals_paused(e) {
+            return 0;
+        }
+        to_assets(e, Base::balance(e, &owner), false).min(Self::idle_assets(e))
     }
 
     fn preview_withdraw(e: &Env, assets: i128) -> i128 {
-        Vault::preview_withdraw(e, assets)
+        to_shares(e, assets, true)
     }
 
     fn max_redeem(e: &Env, owner: Address) -> i128 {
-        Vault::max_redeem(e, owner)
+        if Self::withdrawals_paused(e) {
+            return 0;
+        }
+        Base::balance(e, &owner).min(to_shares(e, Self::idle_assets(e), false))
     }
 
     fn preview_redeem(e: &Env, shares: i128) -> i128 {
-        Vault::preview_redeem(e, shares)
+        to_assets(e, shares, false)
     }
 
     fn deposit(e: &Env, assets: i128, receiver: Address, from: Address, operator: Address) -> i128 {
         operator.require_auth();
-        Vault::deposit(e, assets, receiver, from, operator)
+        when_deposits_not_paused(e);
+        Self::accrue_fees(e);
+        if assets > Self::max_deposit(e, receiver.clone()) {
+            panic_with_error!(e, ExampleContractError::ExceededDepositLimit);
+        }
+        let shares = Self::preview_deposit(e, assets);
+        deposit_internal(e, &receiver, assets, shares, &from, &operator);
+        shares
     }
 
     fn mint(e: &Env, shares: i128, receiver: Address, from: Address, operator: Address) -> i128 {
         operator.require_auth();
-        Vault::mint(e, shares, receiver, from, operator)
+        when_deposits_not_paused(e);
+        Self::accrue_fees(e);
+        if shares > Self::max_mint(e, receiver.clone()) {
+            panic_with_error!(e, ExampleContractError::ExceededDepositLimit);
+        }
+        let assets = Self::preview_mint(e, shares);
+        deposit_internal(e, &receiver, assets, shares, &from, &operator);
+        assets
     }
 
     fn withdraw(
@@ -109,11 +722,220 @@ impl FungibleVault for ExampleContract {
         operator: Address,
     ) -> i128 {
         operator.require_auth();
-        Vault::withdraw(e, assets, receiver, owner, operator)
+        when_withdrawals_not_paused(e);
+        Self::accrue_fees(e);
+        if assets > Self::max_withdraw(e, owner.clone()) {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxWithdraw);
+        }
+        let shares = Self::preview_withdraw(e, assets);
+        withdraw_internal(e, &receiver, &owner, assets, shares, &operator);
+        shares
     }
 
     fn redeem(e: &Env, shares: i128, receiver: Address, owner: Address, operator: Address) -> i128 {
         operator.require_auth();
-        Vault::redeem(e, shares, receiver, owner, operator)
+        when_withdrawals_not_paused(e);
+        Self::accrue_fees(e);
+        if shares > Self::max_redeem(e, owner.clone()) {
+            panic_with_error!(e, VaultTokenError::VaultExceededMaxRedeem);
+        }
+        let assets = Self::preview_redeem(e, shares);
+        withdraw_internal(e, &receiver, &owner, assets, shares, &operator);
+        assets
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl AccessControl for ExampleContract {}
+
+/// Pulls `assets` from `from` and mints `shares` to `receiver`. The
+/// `Vault` flows are not used because they value shares against the idle
+/// balance only, ignoring strategies and pending fees.
+fn deposit_internal(
+    e: &Env,
+    receiver: &Address,
+    assets: i128,
+    shares: i128,
+    from: &Address,
+    operator: &Address,
+) {
+    if assets < 0 {
+        panic_with_error!(e, VaultTokenError::VaultInvalidAssetsAmount);
+    }
+    let asset = token::Client::new(e, &Vault::query_asset(e));
+    if from == operator {
+        asset.transfer(from, &e.current_contract_address(), &assets);
+    } else {
+        asset.transfer_from(operator, from, &e.current_contract_address(), &assets);
+    }
+    Base::update(e, None, Some(receiver), shares);
+    DepositEvent {
+        operator: operator.clone(),
+        from: from.clone(),
+        receiver: receiver.clone(),
+        assets,
+        shares,
+    }
+    .publish(e);
+}
+
+/// Burns `shares` from `owner`, spending the operator's allowance if needed,
+/// and pays `assets` from the idle balance to `receiver`.
+fn withdraw_internal(
+    e: &Env,
+    receiver: &Address,
+    owner: &Address,
+    assets: i128,
+    shares: i128,
+    operator: &Address,
+) {
+    if shares < 0 {
+        panic_with_error!(e, VaultTokenError::VaultInvalidSharesAmount);
+    }
+    if operator != owner {
+        Base::spend_allowance(e, owner, operator, shares);
+    }
+    Base::update(e, Some(owner), None, shares);
+    token::Client::new(e, &Vault::query_asset(e)).transfer(
+        &e.current_contract_address(),
+        receiver,
+        &assets,
+    );
+    WithdrawEvent {
+        operator: operator.clone(),
+        receiver: receiver.clone(),
+        owner: owner.clone(),
+        assets,
+        shares,
+    }
+    .publish(e);
+}
+
+fn queue_head(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::QueueHead).unwrap_or(0)
+}
+
+fn queue_tail(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::QueueTail).unwrap_or(0)
+}
+
+fn set_or_remove_balance(e: &Env, key: &DataKey, amount: i128) {
+    if amount == 0 {
+        e.storage().persistent().remove(key);
+    } else {
+        e.storage().persistent().set(key, &amount);
+    }
+}
+
+fn check_amount(e: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, ExampleContractError::InvalidAmount);
+    }
+}
+
+fn check_cap(e: &Env, cap: Option<i128>) {
+    if cap.is_some_and(|cap| cap < 0) {
+        panic_with_error!(e, ExampleContractError::InvalidCap);
+    }
+}
+
+fn set_or_remove(e: &Env, key: &DataKey, cap: Option<i128>) {
+    match cap {
+        Some(cap) => e.storage().instance().set(key, &cap),
+        None => e.storage().instance().remove(key),
+    }
+}
+
+fn when_deposits_not_paused(e: &Env) {
+    if ExampleContract::deposits_paused(e) {
+        panic_with_error!(e, ExampleContractError::DepositsPaused);
+    }
+}
+
+fn when_withdrawals_not_paused(e: &Env) {
+    if ExampleContract::withdrawals_paused(e) {
+        panic_with_error!(e, ExampleContractError::WithdrawalsPaused);
+    }
+}
+
+/// Returns the fee shares to mint now and the high-water mark after minting
+/// them.
+fn pending_fees(e: &Env) -> (i128, i128) {
+    let high_water_mark = ExampleContract::high_water_mark(e);
+    let supply = Base::total_supply(e);
+    if supply == 0 {
+        return (0, high_water_mark);
+    }
+    let total_assets = ExampleContract::total_assets(e);
+
+    let last_accrual: u64 = e.storage().instance().get(&DataKey::LastAccrual).unwrap_or(0);
+    let elapsed = e.ledger().timestamp().saturating_sub(last_accrual);
+    let management_fee_bps = ExampleContract::management_fee_bps(e) as i128;
+    let mut fee_assets = mul_div(
+        e,
+        total_assets,
+        management_fee_bps * elapsed as i128,
+        BPS_DENOMINATOR as i128 * SECONDS_PER_YEAR as i128,
+        false,
+    );
+
+    let price = share_price(e, total_assets, supply);
+    if price > high_water_mark {
+        let gain =
+            mul_div(e, price - high_water_mark, supply + virtual_shares(e), PRICE_SCALE, false);
+        let performance_fee_bps = ExampleContract::performance_fee_bps(e) as i128;
+        fee_assets += mul_div(e, gain, performance_fee_bps, BPS_DENOMINATOR as i128, false);
+    }
+    let fee_assets = fee_assets.min(total_assets);
+
+    // Mint the shares worth `fee_assets` after dilution:
+    // shares / (supply + shares) = fee_assets / total_assets
+    let shares = mul_div(
+        e,
+        fee_assets,
+        supply + virtual_shares(e),
+        total_assets + 1 - fee_assets,
+        false,
+    );
+    let price_after = share_price(e, total_assets, supply + shares);
+    (shares, high_water_mark.max(price_after))
+}
+
+/// Virtual shares added to the supply by the decimals offset, as in the
+/// `Vault` conversions.
+fn virtual_shares(e: &Env) -> i128 {
+    10i128.pow(Vault::get_decimals_offset(e))
+}
+
+/// Assets per share, scaled by `PRICE_SCALE`.
+fn share_price(e: &Env, total_assets: i128, supply: i128) -> i128 {
+    mul_div(e, total_assets + 1, PRICE_SCALE, supply + virtual_shares(e), false)
+}
+
+/// Same as `Vault::convert_to_shares`, over all assets including strategies
+/// and with the pending fee shares minted.
+fn to_shares(e: &Env, assets: i128, round_up: bool) -> i128 {
+    let supply = Base::total_supply(e) + pending_fees(e).0;
+    mul_div(e, assets, supply + virtual_shares(e), ExampleContract::total_assets(e) + 1, round_up)
+}
+
+/// Same as `Vault::convert_to_assets`, over all assets including strategies
+/// and with the pending fee shares minted.
+fn to_assets(e: &Env, shares: i128, round_up: bool) -> i128 {
+    let supply = Base::total_supply(e) + pending_fees(e).0;
+    mul_div(e, shares, ExampleContract::total_assets(e) + 1, supply + virtual_shares(e), round_up)
+}
+
+/// Computes `x * y / denominator` without intermediate overflow.
+fn mul_div(e: &Env, x: i128, y: i128, denominator: i128, round_up: bool) -> i128 {
+    let product = I256::from_i128(e, x).mul(&I256::from_i128(e, y));
+    let denominator = I256::from_i128(e, denominator);
+    let mut result = product.div(&denominator);
+    if round_up && product.rem_euclid(&denominator) != I256::from_i128(e, 0) {
+        result = result.add(&I256::from_i128(e, 1));
     }
+    result
+        .to_i128()
+        .unwrap_or_else(|| panic_with_error!(e, ExampleContractError::MathOverflow))
 }
@@ -2,4 +2,5 @@
 #![allow(dead_code)]
 
 mod contract;
+mod strategy;
 mod test;
@@ -0,0 +1,21 @@
+//! Strategy interface used by the vault to deploy idle assets.
+//!
+//! A strategy holds part of the vault's underlying asset and reports its
+//! value back in that asset. The vault transfers assets to the strategy
+//! before calling `deposit`, and expects `withdraw` to send assets back to
+//! it. Strategies should only accept calls authorized by the vault.
+
+use soroban_sdk::{contractclient, Env};
+
+#[contractclient(name = "StrategyClient")]
+pub trait Strategy {
+    /// Notifies the strategy that the vault transferred `amount` of the
+    /// asset to it.
+    fn deposit(e: &Env, amount: i128);
+
+    /// Transfers `amount` of the asset from the strategy back to the vault.
+    fn withdraw(e: &Env, amount: i128);
+
+    /// Returns the value held by the strategy for the vault, in the asset.
+    fn total_value(e: &Env) -> i128;
+}
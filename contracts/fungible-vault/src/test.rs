@@ -2,11 +2,16 @@
 
 extern crate std;
 
-use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
 use stellar_macros::default_impl;
 use stellar_tokens::fungible::{Base, FungibleToken};
+use vault_mock_strategy::contract::{MockStrategyContract, MockStrategyContractClient};
 
-use crate::contract::{ExampleContract, ExampleContractClient};
+use crate::contract::{ExampleContract, ExampleContractClient, SECONDS_PER_YEAR};
 
 // Mock Asset Contract - Simple fungible token to use as underlying asset
 #[contract]
@@ -39,7 +44,48 @@ fn create_vault_client<'a>(
     asset_address: &Address,
     decimals_offset: u32,
 ) -> ExampleContractClient<'a> {
-    let vault_address = e.register(ExampleContract, (asset_address, decimals_offset));
+    let admin = Address::generate(e);
+    let curator = Address::generate(e);
+    create_vault_with_roles(e, asset_address, decimals_offset, &admin, &curator)
+}
+
+fn create_vault_with_roles<'a>(
+    e: &Env,
+    asset_address: &Address,
+    decimals_offset: u32,
+    admin: &Address,
+    curator: &Address,
+) -> ExampleContractClient<'a> {
+    create_vault_with_fees(e, asset_address, decimals_offset, admin, curator, admin, 0, 0, 0)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_vault_with_fees<'a>(
+    e: &Env,
+    asset_address: &Address,
+    decimals_offset: u32,
+    admin: &Address,
+    curator: &Address,
+    fee_recipient: &Address,
+    management_fee_bps: u32,
+    performance_fee_bps: u32,
+    max_fee_bps: u32,
+) -> ExampleContractClient<'a> {
+    let vault_address = e.register(
+        ExampleContract,
+        (
+            asset_address,
+            decimals_offset,
+            String::from_str(e, "Vault Token"),
+            String::from_str(e, "VLT"),
+            admin,
+            curator,
+            fee_recipient,
+            management_fee_bps,
+            performance_fee_bps,
+            max_fee_bps,
+        ),
+    );
     ExampleContractClient::new(e, &vault_address)
 }
 
@@ -69,6 +115,8 @@ fn test_vault_initialization() {
     // Test vault initialization
     assert_eq!(vault_client.query_asset(), asset_address);
     assert_eq!(vault_client.decimals(), 18 + decimals_offset); // asset decimals + offset
+    assert_eq!(vault_client.name(), String::from_str(&e, "Vault Token"));
+    assert_eq!(vault_client.symbol(), String::from_str(&e, "VLT"));
     assert_eq!(vault_client.total_supply(), 0);
     assert_eq!(vault_client.total_assets(), 0);
 }
@@ -396,3 +444,642 @@ fn test_redeem_exceeds_max() {
     // Try to redeem more shares than user has
     vault_client.redeem(&(shares + 1), &user, &user, &user);
 }
+
+// ===== Risk Limit Tests =====
+
+struct LimitsSetup<'a> {
+    vault: ExampleContractClient<'a>,
+    asset: MockAssetContractClient<'a>,
+    admin: Address,
+    curator: Address,
+}
+
+fn setup_limits<'a>(e: &Env) -> LimitsSetup<'a> {
+    let admin = Address::generate(e);
+    let curator = Address::generate(e);
+    let asset = create_asset_client(e, 1_000_000, &admin);
+    let vault = create_vault_with_roles(e, &asset.address, 0, &admin, &curator);
+    LimitsSetup { vault, asset, admin, curator }
+}
+
+#[test]
+fn test_tvl_cap_limits_deposits() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+
+    s.vault.set_tvl_cap(&Some(1_000), &s.curator);
+    assert_eq!(s.vault.tvl_cap(), Some(1_000));
+    assert_eq!(s.vault.max_deposit(&user), 1_000);
+    assert_eq!(s.vault.max_mint(&user), 1_000);
+
+    s.vault.deposit(&600, &user, &user, &user);
+    assert_eq!(s.vault.max_deposit(&user), 400);
+    assert_eq!(s.vault.max_deposit(&s.admin), 400);
+
+    s.vault.set_tvl_cap(&None, &s.curator);
+    assert_eq!(s.vault.max_deposit(&user), i128::MAX);
+    assert_eq!(s.vault.max_mint(&user), i128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_deposit_above_tvl_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+
+    s.vault.set_tvl_cap(&Some(1_000), &s.curator);
+    s.vault.deposit(&1_001, &user, &user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_mint_above_tvl_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+
+    s.vault.set_tvl_cap(&Some(1_000), &s.curator);
+    s.vault.mint(&1_001, &user, &user, &user);
+}
+
+#[test]
+fn test_receiver_caps() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    let whale = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.asset.transfer(&s.admin, &whale, &10_000);
+
+    s.vault.set_default_receiver_cap(&Some(500), &s.curator);
+    s.vault.set_receiver_cap(&whale, &Some(5_000), &s.curator);
+    assert_eq!(s.vault.receiver_cap(&user), Some(500));
+    assert_eq!(s.vault.receiver_cap(&whale), Some(5_000));
+
+    s.vault.deposit(&300, &user, &user, &user);
+    assert_eq!(s.vault.max_deposit(&user), 200);
+
+    // The cap applies to the receiver, whoever pays
+    s.vault.deposit(&200, &user, &whale, &whale);
+    assert_eq!(s.vault.max_deposit(&user), 0);
+    assert_eq!(s.vault.max_mint(&user), 0);
+
+    s.vault.deposit(&4_000, &whale, &whale, &whale);
+    assert_eq!(s.vault.max_deposit(&whale), 1_000);
+
+    // Removing the override falls back to the default cap
+    s.vault.set_receiver_cap(&whale, &None, &s.curator);
+    assert_eq!(s.vault.max_deposit(&whale), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_deposit_above_receiver_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+
+    s.vault.set_default_receiver_cap(&Some(500), &s.curator);
+    s.vault.deposit(&300, &user, &user, &user);
+    s.vault.deposit(&201, &user, &user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_negative_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+
+    s.vault.set_tvl_cap(&Some(-1), &s.curator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_set_cap_requires_curator() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+
+    s.vault.set_tvl_cap(&Some(1_000), &s.admin);
+}
+
+#[test]
+fn test_pause_switches_are_independent() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&1_000, &user, &user, &user);
+
+    s.vault.set_deposits_paused(&true);
+    assert!(s.vault.deposits_paused());
+    assert_eq!(s.vault.max_deposit(&user), 0);
+    assert_eq!(s.vault.max_mint(&user), 0);
+
+    // Withdrawals still work while deposits are paused
+    assert_eq!(s.vault.max_redeem(&user), 1_000);
+    s.vault.withdraw(&500, &user, &user, &user);
+
+    s.vault.set_deposits_paused(&false);
+    s.vault.set_withdrawals_paused(&true);
+    assert_eq!(s.vault.max_withdraw(&user), 0);
+    assert_eq!(s.vault.max_redeem(&user), 0);
+    s.vault.deposit(&500, &user, &user, &user);
+    assert_eq!(s.vault.balance(&user), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_deposit_when_paused_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+
+    s.vault.set_deposits_paused(&true);
+    s.vault.deposit(&100, &user, &user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_redeem_when_paused_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&100, &user, &user, &user);
+
+    s.vault.set_withdrawals_paused(&true);
+    s.vault.redeem(&100, &user, &user, &user);
+}
+
+// ===== Fee Tests =====
+
+struct FeesSetup<'a> {
+    vault: ExampleContractClient<'a>,
+    asset: MockAssetContractClient<'a>,
+    admin: Address,
+    curator: Address,
+    fee_recipient: Address,
+    user: Address,
+}
+
+fn setup_fees<'a>(e: &Env, management_fee_bps: u32, performance_fee_bps: u32) -> FeesSetup<'a> {
+    let admin = Address::generate(e);
+    let curator = Address::generate(e);
+    let fee_recipient = Address::generate(e);
+    let user = Address::generate(e);
+    let asset = create_asset_client(e, 1_000_000, &admin);
+    let vault = create_vault_with_fees(
+        e,
+        &asset.address,
+        0,
+        &admin,
+        &curator,
+        &fee_recipient,
+        management_fee_bps,
+        performance_fee_bps,
+        2_000,
+    );
+    asset.transfer(&admin, &user, &100_000);
+    FeesSetup { vault, asset, admin, curator, fee_recipient, user }
+}
+
+fn advance_time(e: &Env, seconds: u64) {
+    e.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_management_fee_accrues_over_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 200, 0);
+    s.vault.deposit(&10_000, &s.user, &s.user, &s.user);
+    assert_eq!(s.vault.pending_fee_shares(), 0);
+
+    // 2% of 10_000 assets over one year
+    advance_time(&e, SECONDS_PER_YEAR);
+    let pending = s.vault.pending_fee_shares();
+    assert_eq!(s.vault.accrue_fees(), pending);
+    assert_eq!(s.vault.balance(&s.fee_recipient), pending);
+    let fee_assets = s.vault.convert_to_assets(&pending);
+    assert!((198..=200).contains(&fee_assets));
+
+    // Nothing more is due in the same ledger
+    assert_eq!(s.vault.accrue_fees(), 0);
+}
+
+#[test]
+fn test_performance_fee_above_high_water_mark() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 0, 1_000);
+    s.vault.deposit(&10_000, &s.user, &s.user, &s.user);
+
+    // Yield of 1_000 assets: 10% of it goes to the fee recipient
+    s.asset.transfer(&s.admin, &s.vault.address, &1_000);
+    let hwm = s.vault.high_water_mark();
+    let shares = s.vault.accrue_fees();
+    assert!(shares > 0);
+    let fee_assets = s.vault.convert_to_assets(&shares);
+    assert!((98..=100).contains(&fee_assets));
+    assert!(s.vault.high_water_mark() > hwm);
+
+    // Gains already charged are not charged again
+    assert_eq!(s.vault.accrue_fees(), 0);
+
+    // Only new gains above the high-water mark are charged
+    s.asset.transfer(&s.admin, &s.vault.address, &1_000);
+    assert!(s.vault.accrue_fees() > 0);
+}
+
+#[test]
+fn test_previews_include_pending_fees() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 500, 2_000);
+    let shares = s.vault.deposit(&10_000, &s.user, &s.user, &s.user);
+
+    advance_time(&e, SECONDS_PER_YEAR / 2);
+    s.asset.transfer(&s.admin, &s.vault.address, &3_000);
+    assert!(s.vault.pending_fee_shares() > 0);
+
+    let preview = s.vault.preview_redeem(&(shares / 2));
+    assert_eq!(s.vault.redeem(&(shares / 2), &s.user, &s.user, &s.user), preview);
+    assert_eq!(s.vault.pending_fee_shares(), 0);
+
+    advance_time(&e, SECONDS_PER_YEAR / 2);
+    let preview = s.vault.preview_deposit(&1_000);
+    assert_eq!(s.vault.deposit(&1_000, &s.user, &s.user, &s.user), preview);
+
+    advance_time(&e, SECONDS_PER_YEAR / 2);
+    let preview = s.vault.preview_withdraw(&1_000);
+    assert_eq!(s.vault.withdraw(&1_000, &s.user, &s.user, &s.user), preview);
+}
+
+#[test]
+fn test_set_fee_rates_accrues_at_old_rates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 200, 0);
+    s.vault.deposit(&10_000, &s.user, &s.user, &s.user);
+
+    advance_time(&e, SECONDS_PER_YEAR);
+    let pending = s.vault.pending_fee_shares();
+    s.vault.set_fee_rates(&0, &0, &s.curator);
+    assert_eq!(s.vault.balance(&s.fee_recipient), pending);
+    assert_eq!(s.vault.management_fee_bps(), 0);
+
+    advance_time(&e, SECONDS_PER_YEAR);
+    assert_eq!(s.vault.pending_fee_shares(), 0);
+}
+
+#[test]
+fn test_set_fee_recipient() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 200, 0);
+    let new_recipient = Address::generate(&e);
+    s.vault.deposit(&10_000, &s.user, &s.user, &s.user);
+
+    advance_time(&e, SECONDS_PER_YEAR);
+    s.vault.set_fee_recipient(&new_recipient);
+    assert_eq!(s.vault.fee_recipient(), new_recipient);
+    assert!(s.vault.balance(&s.fee_recipient) > 0);
+
+    advance_time(&e, SECONDS_PER_YEAR);
+    s.vault.accrue_fees();
+    assert!(s.vault.balance(&new_recipient) > 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_fee_rate_above_max_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 0, 0);
+
+    s.vault.set_fee_rates(&0, &2_001, &s.curator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_max_fee_above_100_percent_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let asset = create_asset_client(&e, 1_000_000, &admin);
+
+    create_vault_with_fees(&e, &asset.address, 0, &admin, &admin, &admin, 0, 0, 10_001);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_set_fee_rates_requires_curator() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_fees(&e, 0, 0);
+
+    s.vault.set_fee_rates(&100, &100, &s.admin);
+}
+
+// ===== Strategy Tests =====
+
+fn create_strategy_client<'a>(
+    e: &Env,
+    vault: &ExampleContractClient,
+    asset: &MockAssetContractClient,
+) -> MockStrategyContractClient<'a> {
+    let strategy_address = e.register(MockStrategyContract, (&vault.address, &asset.address));
+    MockStrategyContractClient::new(e, &strategy_address)
+}
+
+#[test]
+fn test_strategy_allocation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.add_strategy(&strategy.address, &6_000, &s.curator);
+    assert_eq!(s.vault.strategies().len(), 1);
+    assert_eq!(s.vault.strategy_cap(&strategy.address), 6_000);
+
+    s.vault.allocate(&strategy.address, &5_000, &s.curator);
+    assert_eq!(strategy.total_value(), 5_000);
+    assert_eq!(s.vault.idle_assets(), 5_000);
+    assert_eq!(s.vault.total_assets(), 10_000);
+
+    // Withdrawals are limited to the idle balance
+    assert_eq!(s.vault.max_withdraw(&user), 5_000);
+    assert_eq!(s.vault.max_redeem(&user), 5_000);
+
+    // Strategy yield raises the share price
+    s.asset.transfer(&s.admin, &strategy.address, &1_000);
+    assert_eq!(s.vault.total_assets(), 11_000);
+    assert!(s.vault.convert_to_assets(&10_000) > 10_000);
+
+    s.vault.deallocate(&strategy.address, &6_000, &s.curator);
+    assert_eq!(strategy.total_value(), 0);
+    assert_eq!(s.vault.idle_assets(), 11_000);
+
+    s.vault.remove_strategy(&strategy.address, &s.curator);
+    assert_eq!(s.vault.strategies().len(), 0);
+
+    let preview = s.vault.preview_redeem(&5_000);
+    assert_eq!(s.vault.redeem(&5_000, &user, &user, &user), preview);
+}
+
+#[test]
+fn test_redeem_with_assets_in_strategy() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.add_strategy(&strategy.address, &10_000, &s.curator);
+    s.vault.allocate(&strategy.address, &4_000, &s.curator);
+    s.asset.transfer(&s.admin, &strategy.address, &2_000);
+
+    // Shares are valued against the strategy too
+    let preview = s.vault.preview_redeem(&5_000);
+    assert_eq!(s.vault.redeem(&5_000, &user, &user, &user), preview);
+    assert!(preview > 5_000);
+    assert_eq!(s.asset.balance(&user), preview);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #407)")]
+fn test_withdraw_above_idle_assets_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.add_strategy(&strategy.address, &10_000, &s.curator);
+    s.vault.allocate(&strategy.address, &8_000, &s.curator);
+    s.vault.withdraw(&2_001, &user, &user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_allocate_above_strategy_cap_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.add_strategy(&strategy.address, &5_000, &s.curator);
+    s.vault.allocate(&strategy.address, &3_000, &s.curator);
+    s.vault.allocate(&strategy.address, &2_001, &s.curator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_allocate_above_idle_assets_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &1_000);
+    s.vault.deposit(&1_000, &user, &user, &user);
+
+    s.vault.add_strategy(&strategy.address, &5_000, &s.curator);
+    s.vault.allocate(&strategy.address, &1_001, &s.curator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_add_strategy_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+
+    s.vault.add_strategy(&strategy.address, &5_000, &s.curator);
+    s.vault.add_strategy(&strategy.address, &5_000, &s.curator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_remove_strategy_with_assets_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &1_000);
+    s.vault.deposit(&1_000, &user, &user, &user);
+
+    s.vault.add_strategy(&strategy.address, &5_000, &s.curator);
+    s.vault.allocate(&strategy.address, &500, &s.curator);
+    s.vault.remove_strategy(&strategy.address, &s.curator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_allocate_requires_curator() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+
+    s.vault.add_strategy(&strategy.address, &5_000, &s.curator);
+    s.vault.allocate(&strategy.address, &100, &s.admin);
+}
+
+// ===== Redemption Queue Tests =====
+
+#[test]
+fn test_request_fulfill_and_claim_redemption() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+    s.vault.add_strategy(&strategy.address, &10_000, &s.curator);
+    s.vault.allocate(&strategy.address, &8_000, &s.curator);
+
+    let request_id = s.vault.request_redeem(&5_000, &user, &user);
+    assert_eq!(request_id, 0);
+    assert_eq!(s.vault.pending_redeem_shares(&user), 5_000);
+    assert_eq!(s.vault.balance(&user), 5_000);
+    assert_eq!(s.vault.balance(&s.vault.address), 5_000);
+    assert_eq!(s.vault.queued_requests(), 1);
+
+    // Not enough idle assets yet
+    assert_eq!(s.vault.fulfill_redemptions(&10, &s.curator), 0);
+
+    s.vault.deallocate(&strategy.address, &4_000, &s.curator);
+    assert_eq!(s.vault.fulfill_redemptions(&10, &s.curator), 1);
+    assert_eq!(s.vault.pending_redeem_shares(&user), 0);
+    assert_eq!(s.vault.claimable_redeem_assets(&user), 5_000);
+    assert_eq!(s.vault.redeem_request(&request_id), None);
+    assert_eq!(s.vault.queued_requests(), 0);
+
+    // Assets set aside for the claim are no longer part of the vault
+    assert_eq!(s.vault.total_assets(), 5_000);
+    assert_eq!(s.vault.idle_assets(), 1_000);
+    assert_eq!(s.vault.max_withdraw(&user), 1_000);
+
+    assert_eq!(s.vault.claim(&user, &user), 5_000);
+    assert_eq!(s.asset.balance(&user), 5_000);
+    assert_eq!(s.vault.claimable_redeem_assets(&user), 0);
+    assert_eq!(s.vault.total_assets(), 5_000);
+}
+
+#[test]
+fn test_redemptions_are_fulfilled_in_order() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let strategy = create_strategy_client(&e, &s.vault, &s.asset);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    s.asset.transfer(&s.admin, &first, &5_000);
+    s.asset.transfer(&s.admin, &second, &5_000);
+    s.vault.deposit(&5_000, &first, &first, &first);
+    s.vault.deposit(&5_000, &second, &second, &second);
+    s.vault.add_strategy(&strategy.address, &10_000, &s.curator);
+    s.vault.allocate(&strategy.address, &6_000, &s.curator);
+
+    s.vault.request_redeem(&3_000, &first, &first);
+    s.vault.request_redeem(&3_000, &second, &second);
+
+    assert_eq!(s.vault.fulfill_redemptions(&10, &s.curator), 1);
+    assert_eq!(s.vault.claimable_redeem_assets(&first), 3_000);
+    assert_eq!(s.vault.pending_redeem_shares(&second), 3_000);
+    assert_eq!(s.vault.queued_requests(), 1);
+
+    // The batch size bounds the requests fulfilled in one call
+    s.vault.deallocate(&strategy.address, &6_000, &s.curator);
+    s.vault.request_redeem(&1_000, &first, &first);
+    assert_eq!(s.vault.fulfill_redemptions(&1, &s.curator), 1);
+    assert_eq!(s.vault.claimable_redeem_assets(&second), 3_000);
+    assert_eq!(s.vault.pending_redeem_shares(&first), 1_000);
+}
+
+#[test]
+fn test_escrowed_shares_are_priced_at_fulfillment() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.request_redeem(&5_000, &user, &user);
+    s.asset.transfer(&s.admin, &s.vault.address, &1_000);
+
+    let expected = s.vault.preview_redeem(&5_000);
+    assert!(expected > 5_000);
+    s.vault.fulfill_redemptions(&10, &s.curator);
+    assert_eq!(s.vault.claimable_redeem_assets(&user), expected);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_claim_without_fulfilled_request_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.request_redeem(&5_000, &user, &user);
+    s.vault.claim(&user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_request_redeem_when_paused_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+    let user = Address::generate(&e);
+    s.asset.transfer(&s.admin, &user, &10_000);
+    s.vault.deposit(&10_000, &user, &user, &user);
+
+    s.vault.set_withdrawals_paused(&true);
+    s.vault.request_redeem(&5_000, &user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_fulfill_redemptions_requires_curator() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup_limits(&e);
+
+    s.vault.fulfill_redemptions(&10, &s.admin);
+}
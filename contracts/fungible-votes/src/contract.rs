@@ -0,0 +1,405 @@
+//! Fungible Votes Example Contract.
+
+//! This contract showcases a SEP-41-compliant fungible token that keeps a
+//! history of voting power, so governance contracts can read it on-chain
+//! instead of relying on an off-chain snapshot.
+//!
+//! Holders must delegate to activate their voting power, either to themselves
+//! or to another account. Delegation can be done directly with `delegate`, or
+//! by a relayer with `delegate_by_sig` using an ed25519 signature from a key
+//! the holder registered beforehand.
+//!
+//! The same registered key can sign approvals, which anyone can submit with
+//! `permit`, so a relayer can sponsor approvals for holders without XLM.
+//!
+//! Every change in voting power is recorded as a checkpoint keyed by ledger
+//! sequence. `get_past_votes` and `get_past_total_supply` look up the value
+//! at the end of a past ledger, which is what a proposal should use as its
+//! snapshot.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error,
+    symbol_short, xdr::ToXdr, Address, BytesN, Env, String,
+};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_macros::{default_impl, only_admin};
+use stellar_tokens::fungible::{burnable::FungibleBurnable, emit_approve, Base, FungibleToken};
+
+/// Whose history a checkpoint belongs to.
+#[contracttype]
+#[derive(Clone)]
+pub enum CheckpointOwner {
+    Account(Address),
+    TotalSupply,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub votes: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Delegate(Address),
+    NumCheckpoints(CheckpointOwner),
+    Checkpoint(CheckpointOwner, u32),
+}
+
+#[contractevent]
+pub struct DelegateChangedEvent {
+    #[topic]
+    pub delegator: Address,
+    pub from_delegate: Option<Address>,
+    pub to_delegate: Address,
+}
+
+#[contractevent]
+pub struct DelegateVotesChangedEvent {
+    #[topic]
+    pub delegate: Address,
+    pub previous_votes: i128,
+    pub new_votes: i128,
+}
+
+#[contract]
+pub struct ExampleContract;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    FutureLookup = 1,
+    SignatureExpired = 2,
+}
+
+#[contractimpl]
+impl ExampleContract {
+    pub fn __constructor(
+        e: &Env,
+        admin: Address,
+        initial_supply: i128,
+        name: String,
+        symbol: String,
+        decimals: u32,
+    ) {
+        Base::set_metadata(e, decimals, name, symbol);
+
+        access_control::set_admin(e, &admin);
+
+        // Mint initial supply to the admin
+        Base::mint(e, &admin, initial_supply);
+        write_supply_checkpoint(e, initial_supply);
+    }
+
+    #[only_admin]
+    pub fn mint(e: &Env, account: Address, amount: i128) {
+        Base::mint(e, &account, amount);
+        move_voting_power(e, None, get_delegate(e, &account).as_ref(), amount);
+        write_supply_checkpoint(e, amount);
+    }
+
+    /// Returns the account `account` delegates its voting power to, if any.
+    pub fn delegates(e: &Env, account: Address) -> Option<Address> {
+        get_delegate(e, &account)
+    }
+
+    /// Delegates the voting power of `account` to `delegatee`.
+    pub fn delegate(e: &Env, account: Address, delegatee: Address) {
+        account.require_auth();
+        delegate(e, &account, &delegatee);
+    }
+
+    /// Delegates on behalf of `account` using an ed25519 signature over
+    /// `("delegate", token, account, delegatee, nonce, expiration_ledger)`,
+    /// serialized as XDR, made with the key registered by `account`.
+    pub fn delegate_by_sig(
+        e: &Env,
+        account: Address,
+        delegatee: Address,
+        nonce: u64,
+        expiration_ledger: u32,
+        signature: BytesN<64>,
+    ) {
+        if e.ledger().sequence() > expiration_ledger {
+            panic_with_error!(e, ExampleContractError::SignatureExpired);
+        }
+
+        let payload = (
+            symbol_short!("delegate"),
+            e.current_contract_address(),
+            account.clone(),
+            delegatee.clone(),
+            nonce,
+            expiration_ledger,
+        );
+        signatures::verify_signature(e, &account, nonce, payload.to_xdr(e), &signature);
+
+        delegate(e, &account, &delegatee);
+    }
+
+    /// Approves `spender` on behalf of `owner` using an ed25519 signature
+    /// over `("permit", token, owner, spender, amount, live_until_ledger,
+    /// nonce, deadline)`, serialized as XDR, made with the key registered by
+    /// `owner`. The signature cannot be used after ledger `deadline`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit(
+        e: &Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        live_until_ledger: u32,
+        nonce: u64,
+        deadline: u32,
+        signature: BytesN<64>,
+    ) {
+        if e.ledger().sequence() > deadline {
+            panic_with_error!(e, ExampleContractError::SignatureExpired);
+        }
+
+        let payload = (
+            symbol_short!("permit"),
+            e.current_contract_address(),
+            owner.clone(),
+            spender.clone(),
+            amount,
+            live_until_ledger,
+            nonce,
+            deadline,
+        );
+        signatures::verify_signature(e, &owner, nonce, payload.to_xdr(e), &signature);
+
+        Base::set_allowance(e, &owner, &spender, amount, live_until_ledger);
+        emit_approve(e, &owner, &spender, amount, live_until_ledger);
+    }
+
+    /// Registers the ed25519 public key `account` signs off-chain messages
+    /// with. Registering again replaces the previous key.
+    pub fn register_public_key(e: &Env, account: Address, public_key: BytesN<32>) {
+        account.require_auth();
+        signatures::set_public_key(e, &account, &public_key);
+    }
+
+    pub fn public_key(e: &Env, account: Address) -> Option<BytesN<32>> {
+        signatures::public_key(e, &account)
+    }
+
+    /// Returns the nonce the next signature from `account` must use.
+    pub fn nonce(e: &Env, account: Address) -> u64 {
+        signatures::nonce(e, &account)
+    }
+
+    /// Returns the current voting power of `account`.
+    pub fn get_votes(e: &Env, account: Address) -> i128 {
+        latest(e, &CheckpointOwner::Account(account))
+    }
+
+    /// Returns the voting power of `account` at the end of `ledger`, which
+    /// must already be closed.
+    pub fn get_past_votes(e: &Env, account: Address, ledger: u32) -> i128 {
+        check_past_ledger(e, ledger);
+        lookup(e, &CheckpointOwner::Account(account), ledger)
+    }
+
+    /// Returns the total supply at the end of `ledger`, which must already be
+    /// closed.
+    pub fn get_past_total_supply(e: &Env, ledger: u32) -> i128 {
+        check_past_ledger(e, ledger);
+        lookup(e, &CheckpointOwner::TotalSupply, ledger)
+    }
+
+    pub fn num_checkpoints(e: &Env, account: Address) -> u32 {
+        num_checkpoints(e, &CheckpointOwner::Account(account))
+    }
+
+    pub fn checkpoint(e: &Env, account: Address, index: u32) -> Option<Checkpoint> {
+        e.storage().persistent().get(&DataKey::Checkpoint(CheckpointOwner::Account(account), index))
+    }
+}
+
+#[contractimpl]
+impl FungibleToken for ExampleContract {
+    type ContractType = Base;
+
+    fn total_supply(e: &Env) -> i128 {
+        Self::ContractType::total_supply(e)
+    }
+
+    fn balance(e: &Env, account: Address) -> i128 {
+        Self::ContractType::balance(e, &account)
+    }
+
+    fn allowance(e: &Env, owner: Address, spender: Address) -> i128 {
+        Self::ContractType::allowance(e, &owner, &spender)
+    }
+
+    fn transfer(e: &Env, from: Address, to: Address, amount: i128) {
+        Self::ContractType::transfer(e, &from, &to, amount);
+        move_voting_power(
+            e,
+            get_delegate(e, &from).as_ref(),
+            get_delegate(e, &to).as_ref(),
+            amount,
+        );
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, amount: i128) {
+        Self::ContractType::transfer_from(e, &spender, &from, &to, amount);
+        move_voting_power(
+            e,
+            get_delegate(e, &from).as_ref(),
+            get_delegate(e, &to).as_ref(),
+            amount,
+        );
+    }
+
+    fn approve(e: &Env, owner: Address, spender: Address, amount: i128, live_until_ledger: u32) {
+        Self::ContractType::approve(e, &owner, &spender, amount, live_until_ledger);
+    }
+
+    fn decimals(e: &Env) -> u32 {
+        Self::ContractType::decimals(e)
+    }
+
+    fn name(e: &Env) -> String {
+        Self::ContractType::name(e)
+    }
+
+    fn symbol(e: &Env) -> String {
+        Self::ContractType::symbol(e)
+    }
+}
+
+#[contractimpl]
+impl FungibleBurnable for ExampleContract {
+    fn burn(e: &Env, from: Address, amount: i128) {
+        Self::ContractType::burn(e, &from, amount);
+        move_voting_power(e, get_delegate(e, &from).as_ref(), None, amount);
+        write_supply_checkpoint(e, -amount);
+    }
+
+    fn burn_from(e: &Env, spender: Address, from: Address, amount: i128) {
+        Self::ContractType::burn_from(e, &spender, &from, amount);
+        move_voting_power(e, get_delegate(e, &from).as_ref(), None, amount);
+        write_supply_checkpoint(e, -amount);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl AccessControl for ExampleContract {}
+
+fn get_delegate(e: &Env, account: &Address) -> Option<Address> {
+    e.storage().persistent().get(&DataKey::Delegate(account.clone()))
+}
+
+fn delegate(e: &Env, account: &Address, delegatee: &Address) {
+    let previous = get_delegate(e, account);
+    e.storage().persistent().set(&DataKey::Delegate(account.clone()), delegatee);
+
+    DelegateChangedEvent {
+        delegator: account.clone(),
+        from_delegate: previous.clone(),
+        to_delegate: delegatee.clone(),
+    }
+    .publish(e);
+
+    let balance = Base::balance(e, account);
+    move_voting_power(e, previous.as_ref(), Some(delegatee), balance);
+}
+
+// Moves `amount` of voting power between delegates. `None` stands for an
+// account that has not delegated (or for the mint/burn side), whose voting
+// power is not tracked.
+fn move_voting_power(e: &Env, from: Option<&Address>, to: Option<&Address>, amount: i128) {
+    if from == to || amount == 0 {
+        return;
+    }
+    if let Some(from) = from {
+        let (previous_votes, new_votes) =
+            push_checkpoint(e, &CheckpointOwner::Account(from.clone()), -amount);
+        DelegateVotesChangedEvent { delegate: from.clone(), previous_votes, new_votes }.publish(e);
+    }
+    if let Some(to) = to {
+        let (previous_votes, new_votes) =
+            push_checkpoint(e, &CheckpointOwner::Account(to.clone()), amount);
+        DelegateVotesChangedEvent { delegate: to.clone(), previous_votes, new_votes }.publish(e);
+    }
+}
+
+fn write_supply_checkpoint(e: &Env, delta: i128) {
+    if delta != 0 {
+        push_checkpoint(e, &CheckpointOwner::TotalSupply, delta);
+    }
+}
+
+// Adds `delta` to the latest value of `owner`, overwriting the last
+// checkpoint if it was written in the current ledger.
+fn push_checkpoint(e: &Env, owner: &CheckpointOwner, delta: i128) -> (i128, i128) {
+    let ledger = e.ledger().sequence();
+    let count = num_checkpoints(e, owner);
+    let last: Option<Checkpoint> = if count > 0 { Some(get_checkpoint(e, owner, count - 1)) } else { None };
+    let previous = last.as_ref().map_or(0, |c| c.votes);
+    let votes = previous + delta;
+
+    let index = match last {
+        Some(c) if c.ledger == ledger => count - 1,
+        _ => {
+            e.storage().persistent().set(&DataKey::NumCheckpoints(owner.clone()), &(count + 1));
+            count
+        }
+    };
+    e.storage()
+        .persistent()
+        .set(&DataKey::Checkpoint(owner.clone(), index), &Checkpoint { ledger, votes });
+
+    (previous, votes)
+}
+
+fn num_checkpoints(e: &Env, owner: &CheckpointOwner) -> u32 {
+    e.storage().persistent().get(&DataKey::NumCheckpoints(owner.clone())).unwrap_or(0)
+}
+
+fn get_checkpoint(e: &Env, owner: &CheckpointOwner, index: u32) -> Checkpoint {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Checkpoint(owner.clone(), index))
+        .expect("checkpoint should exist")
+}
+
+fn latest(e: &Env, owner: &CheckpointOwner) -> i128 {
+    let count = num_checkpoints(e, owner);
+    if count == 0 {
+        0
+    } else {
+        get_checkpoint(e, owner, count - 1).votes
+    }
+}
+
+// Binary search for the last checkpoint written at or before `ledger`.
+fn lookup(e: &Env, owner: &CheckpointOwner, ledger: u32) -> i128 {
+    let mut low = 0;
+    let mut high = num_checkpoints(e, owner);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if get_checkpoint(e, owner, mid).ledger > ledger {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    if high == 0 {
+        0
+    } else {
+        get_checkpoint(e, owner, high - 1).votes
+    }
+}
+
+// Values for the current ledger can still change, so only closed ledgers can
+// be looked up.
+fn check_past_ledger(e: &Env, ledger: u32) {
+    if ledger >= e.ledger().sequence() {
+        panic_with_error!(e, ExampleContractError::FutureLookup);
+    }
+}
@@ -0,0 +1,341 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    xdr::ToXdr,
+    Address, BytesN, Env, String,
+};
+
+use crate::contract::{ExampleContract, ExampleContractClient};
+
+fn create_client<'a>(e: &Env, admin: &Address, initial_supply: i128) -> ExampleContractClient<'a> {
+    let address = e.register(
+        ExampleContract,
+        (admin, initial_supply, String::from_str(e, "Votes Token"), String::from_str(e, "VOTE"), 7u32),
+    );
+    ExampleContractClient::new(e, &address)
+}
+
+fn set_sequence(e: &Env, sequence: u32) {
+    e.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+fn sign_delegation(
+    e: &Env,
+    key: &SigningKey,
+    token: &Address,
+    account: &Address,
+    delegatee: &Address,
+    nonce: u64,
+    expiration_ledger: u32,
+) -> BytesN<64> {
+    let payload = (
+        symbol_short!("delegate"),
+        token.clone(),
+        account.clone(),
+        delegatee.clone(),
+        nonce,
+        expiration_ledger,
+    )
+        .to_xdr(e);
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn votes_require_delegation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    assert_eq!(client.get_votes(&admin), 0);
+    assert_eq!(client.delegates(&admin), None);
+
+    client.delegate(&admin, &admin);
+    assert_eq!(client.delegates(&admin), Some(admin.clone()));
+    assert_eq!(client.get_votes(&admin), 1000);
+}
+
+#[test]
+fn transfers_move_voting_power_between_delegates() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    client.delegate(&admin, &admin);
+    client.delegate(&alice, &bob);
+
+    client.transfer(&admin, &alice, &300);
+    assert_eq!(client.get_votes(&admin), 700);
+    assert_eq!(client.get_votes(&bob), 300);
+    assert_eq!(client.get_votes(&alice), 0);
+
+    // Redelegating moves the whole balance
+    client.delegate(&alice, &alice);
+    assert_eq!(client.get_votes(&bob), 0);
+    assert_eq!(client.get_votes(&alice), 300);
+
+    // Tokens sent to an account without delegate carry no votes
+    let carol = Address::generate(&e);
+    client.transfer(&alice, &carol, &100);
+    assert_eq!(client.get_votes(&alice), 200);
+    assert_eq!(client.get_votes(&carol), 0);
+}
+
+#[test]
+fn mint_and_burn_update_votes_and_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    client.delegate(&admin, &admin);
+    client.mint(&admin, &500);
+    assert_eq!(client.get_votes(&admin), 1500);
+
+    client.burn(&admin, &200);
+    assert_eq!(client.get_votes(&admin), 1300);
+
+    set_sequence(&e, e.ledger().sequence() + 1);
+    assert_eq!(client.get_past_total_supply(&(e.ledger().sequence() - 1)), 1300);
+}
+
+#[test]
+fn past_votes_follow_checkpoints() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    set_sequence(&e, 100);
+    let client = create_client(&e, &admin, 1000);
+    client.delegate(&admin, &admin);
+    client.delegate(&alice, &alice);
+
+    set_sequence(&e, 110);
+    client.transfer(&admin, &alice, &100);
+    // Several changes in one ledger share a checkpoint
+    client.transfer(&admin, &alice, &100);
+
+    set_sequence(&e, 120);
+    client.transfer(&alice, &admin, &50);
+
+    set_sequence(&e, 130);
+    assert_eq!(client.num_checkpoints(&alice), 2);
+    assert_eq!(client.get_past_votes(&alice, &99), 0);
+    assert_eq!(client.get_past_votes(&alice, &109), 0);
+    assert_eq!(client.get_past_votes(&alice, &110), 200);
+    assert_eq!(client.get_past_votes(&alice, &119), 200);
+    assert_eq!(client.get_past_votes(&alice, &120), 150);
+    assert_eq!(client.get_past_votes(&admin, &100), 1000);
+    assert_eq!(client.get_past_votes(&admin, &115), 800);
+    assert_eq!(client.get_past_votes(&admin, &129), 850);
+    assert_eq!(client.get_past_total_supply(&99), 0);
+    assert_eq!(client.get_past_total_supply(&129), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn past_votes_for_current_ledger_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    client.get_past_votes(&admin, &e.ledger().sequence());
+}
+
+#[test]
+fn delegate_by_sig_works() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let expiration = e.ledger().sequence() + 10;
+    let signature = sign_delegation(&e, &key, &client.address, &admin, &delegatee, 0, expiration);
+
+    // Anyone can submit the signed delegation
+    e.set_auths(&[]);
+    client.delegate_by_sig(&admin, &delegatee, &0, &expiration, &signature);
+
+    assert_eq!(client.delegates(&admin), Some(delegatee.clone()));
+    assert_eq!(client.get_votes(&delegatee), 1000);
+    assert_eq!(client.nonce(&admin), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #81)")]
+fn delegate_by_sig_replay_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let expiration = e.ledger().sequence() + 10;
+    let signature = sign_delegation(&e, &key, &client.address, &admin, &delegatee, 0, expiration);
+    client.delegate_by_sig(&admin, &delegatee, &0, &expiration, &signature);
+    client.delegate_by_sig(&admin, &delegatee, &0, &expiration, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto")]
+fn delegate_by_sig_wrong_signer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let other_key = SigningKey::from_bytes(&[8u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let expiration = e.ledger().sequence() + 10;
+    let signature =
+        sign_delegation(&e, &other_key, &client.address, &admin, &delegatee, 0, expiration);
+    client.delegate_by_sig(&admin, &delegatee, &0, &expiration, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn delegate_by_sig_expired_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let delegatee = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let expiration = e.ledger().sequence() + 10;
+    let signature = sign_delegation(&e, &key, &client.address, &admin, &delegatee, 0, expiration);
+    set_sequence(&e, expiration + 1);
+    client.delegate_by_sig(&admin, &delegatee, &0, &expiration, &signature);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign_permit(
+    e: &Env,
+    key: &SigningKey,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    live_until_ledger: u32,
+    nonce: u64,
+    deadline: u32,
+) -> BytesN<64> {
+    let payload = (
+        symbol_short!("permit"),
+        token.clone(),
+        owner.clone(),
+        spender.clone(),
+        amount,
+        live_until_ledger,
+        nonce,
+        deadline,
+    )
+        .to_xdr(e);
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn permit_sets_allowance_without_owner_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let deadline = e.ledger().sequence() + 10;
+    let live_until = e.ledger().sequence() + 100;
+    let signature =
+        sign_permit(&e, &key, &client.address, &admin, &spender, 400, live_until, 0, deadline);
+
+    // The relayer submits the permit, no auth from the owner
+    e.set_auths(&[]);
+    client.permit(&admin, &spender, &400, &live_until, &0, &deadline, &signature);
+    assert_eq!(client.allowance(&admin, &spender), 400);
+    assert_eq!(client.nonce(&admin), 1);
+
+    e.mock_all_auths();
+    client.transfer_from(&spender, &admin, &recipient, &400);
+    assert_eq!(client.balance(&recipient), 400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto")]
+fn permit_with_tampered_amount_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let deadline = e.ledger().sequence() + 10;
+    let live_until = e.ledger().sequence() + 100;
+    let signature =
+        sign_permit(&e, &key, &client.address, &admin, &spender, 400, live_until, 0, deadline);
+    client.permit(&admin, &spender, &1000, &live_until, &0, &deadline, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #81)")]
+fn permit_shares_nonces_with_delegation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_public_key(&admin, &BytesN::from_array(&e, &key.verifying_key().to_bytes()));
+
+    let deadline = e.ledger().sequence() + 10;
+    let delegation = sign_delegation(&e, &key, &client.address, &admin, &admin, 0, deadline);
+    client.delegate_by_sig(&admin, &admin, &0, &deadline, &delegation);
+
+    // Nonce 0 was consumed by the delegation
+    let live_until = e.ledger().sequence() + 100;
+    let signature =
+        sign_permit(&e, &key, &client.address, &admin, &spender, 400, live_until, 0, deadline);
+    client.permit(&admin, &spender, &400, &live_until, &0, &deadline, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #80)")]
+fn permit_without_registered_key_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let client = create_client(&e, &admin, 1000);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let deadline = e.ledger().sequence() + 10;
+    let signature = sign_permit(&e, &key, &client.address, &admin, &spender, 400, 100, 0, deadline);
+    client.permit(&admin, &spender, &400, &100, &0, &deadline, &signature);
+}
@@ -0,0 +1,167 @@
+//! # Merkle Airdrop Contract Example
+//!
+//! This contract distributes SEP-41 tokens to a list of recipients committed
+//! to as a Merkle root, so the list itself never has to be stored on-chain.
+//!
+//! Each leaf of the tree contains:
+//!
+//! - `index: u32` — a unique identifier for the claim
+//! - `account: Address` — the recipient of the tokens
+//! - `amount: i128` — the amount of tokens to receive
+//!
+//! Anyone can submit a `ClaimData` leaf and its Merkle proof before the
+//! deadline; the tokens are always paid to the `account` in the leaf, and each
+//! index can only be claimed once. After the deadline, the admin can sweep the
+//! unclaimed tokens.
+//!
+//! The contract must hold enough tokens to cover every leaf. TokenFactory's
+//! `deploy_airdrop` deploys a new token together with the distributor and
+//! mints the whole initial supply straight into it.
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, token,
+    Address, BytesN, Env, Vec,
+};
+use stellar_contract_utils::{
+    crypto::sha256::Sha256,
+    merkle_distributor::{IndexableLeaf, MerkleDistributor},
+};
+
+type Distributor = MerkleDistributor<Sha256>;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimData {
+    pub index: u32,
+    pub account: Address,
+    pub amount: i128,
+}
+
+impl IndexableLeaf for ClaimData {
+    fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Deadline,
+}
+
+#[contractevent]
+pub struct ClaimedEvent {
+    pub index: u32,
+    pub account: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct SweptEvent {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AirdropError {
+    InvalidDeadline = 1,
+    InvalidAmount = 2,
+    ClaimPeriodEnded = 3,
+    ClaimPeriodActive = 4,
+    NothingToSweep = 5,
+}
+
+#[contract]
+pub struct MerkleAirdrop;
+
+#[contractimpl]
+impl MerkleAirdrop {
+    /// `deadline` is the ledger timestamp after which claims are rejected and
+    /// the admin may sweep the remaining balance.
+    pub fn __constructor(e: &Env, admin: Address, token: Address, root_hash: BytesN<32>, deadline: u64) {
+        if deadline <= e.ledger().timestamp() {
+            panic_with_error!(e, AirdropError::InvalidDeadline);
+        }
+
+        Distributor::set_root(e, root_hash);
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::Token, &token);
+        e.storage().instance().set(&DataKey::Deadline, &deadline);
+    }
+
+    /// Pays `claim.amount` tokens to `claim.account` if `proof` shows the leaf
+    /// is part of the tree and its index has not been claimed yet.
+    pub fn claim(e: &Env, claim: ClaimData, proof: Vec<BytesN<32>>) {
+        if e.ledger().timestamp() > get_deadline(e) {
+            panic_with_error!(e, AirdropError::ClaimPeriodEnded);
+        }
+        if claim.amount <= 0 {
+            panic_with_error!(e, AirdropError::InvalidAmount);
+        }
+
+        // Verify merkle proof and mark the index as claimed
+        Distributor::verify_and_set_claimed(e, claim.clone(), proof);
+
+        token::Client::new(e, &get_token(e)).transfer(
+            &e.current_contract_address(),
+            &claim.account,
+            &claim.amount,
+        );
+
+        ClaimedEvent { index: claim.index, account: claim.account, amount: claim.amount }
+            .publish(e);
+    }
+
+    /// Sends every unclaimed token to the admin once the deadline has passed.
+    ///
+    /// # Returns
+    /// Amount swept
+    pub fn sweep(e: &Env) -> i128 {
+        let admin = get_admin(e);
+        admin.require_auth();
+
+        if e.ledger().timestamp() <= get_deadline(e) {
+            panic_with_error!(e, AirdropError::ClaimPeriodActive);
+        }
+
+        let token = token::Client::new(e, &get_token(e));
+        let amount = token.balance(&e.current_contract_address());
+        if amount <= 0 {
+            panic_with_error!(e, AirdropError::NothingToSweep);
+        }
+        token.transfer(&e.current_contract_address(), &admin, &amount);
+
+        SweptEvent { recipient: admin, amount }.publish(e);
+        amount
+    }
+
+    pub fn is_claimed(e: &Env, index: u32) -> bool {
+        Distributor::is_claimed(e, index)
+    }
+
+    pub fn deadline(e: &Env) -> u64 {
+        get_deadline(e)
+    }
+
+    pub fn token(e: &Env) -> Address {
+        get_token(e)
+    }
+
+    pub fn admin(e: &Env) -> Address {
+        get_admin(e)
+    }
+}
+
+fn get_deadline(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::Deadline).expect("deadline should be set")
+}
+
+fn get_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).expect("admin should be set")
+}
+
+fn get_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token).expect("token should be set")
+}
@@ -0,0 +1,4 @@
+#![no_std]
+pub mod contract;
+#[cfg(test)]
+mod test;
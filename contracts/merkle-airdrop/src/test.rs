@@ -0,0 +1,138 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, BytesN, Env, Vec,
+};
+use stellar_contract_utils::crypto::{
+    hashable::commutative_hash_pair, hasher::Hasher, sha256::Sha256,
+};
+
+use crate::contract::{ClaimData, MerkleAirdrop, MerkleAirdropClient};
+
+const DEADLINE: u64 = 10_000;
+
+fn hash_claim(e: &Env, data: &ClaimData) -> BytesN<32> {
+    let mut hasher = Sha256::new(e);
+    hasher.update(data.clone().to_xdr(e));
+    hasher.finalize()
+}
+
+struct Setup<'a> {
+    client: MerkleAirdropClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    claims: [ClaimData; 2],
+    leaves: [BytesN<32>; 2],
+}
+
+// Two-leaf tree funded with exactly the sum of both claims
+fn setup<'a>(e: &Env) -> Setup<'a> {
+    let admin = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(e, &sac.address());
+
+    let claims = [
+        ClaimData { index: 0, account: Address::generate(e), amount: 100 },
+        ClaimData { index: 1, account: Address::generate(e), amount: 50 },
+    ];
+    let leaves = [hash_claim(e, &claims[0]), hash_claim(e, &claims[1])];
+    let root = commutative_hash_pair(&leaves[0], &leaves[1], Sha256::new(e));
+
+    let address = e.register(MerkleAirdrop, (&admin, &sac.address(), root, DEADLINE));
+    token::StellarAssetClient::new(e, &sac.address()).mint(&address, &150);
+
+    Setup { client: MerkleAirdropClient::new(e, &address), token, admin, claims, leaves }
+}
+
+fn set_timestamp(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn claims_pay_recipients() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    s.client.claim(&s.claims[0], &Vec::from_array(&e, [s.leaves[1].clone()]));
+    s.client.claim(&s.claims[1], &Vec::from_array(&e, [s.leaves[0].clone()]));
+
+    assert!(s.client.is_claimed(&0));
+    assert!(s.client.is_claimed(&1));
+    assert_eq!(s.token.balance(&s.claims[0].account), 100);
+    assert_eq!(s.token.balance(&s.claims[1].account), 50);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1301)")] // MerkleDistributor IndexAlreadyClaimed
+fn double_claim_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    let proof = Vec::from_array(&e, [s.leaves[1].clone()]);
+    s.client.claim(&s.claims[0], &proof);
+    s.client.claim(&s.claims[0], &proof);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1302)")] // MerkleDistributor InvalidProof
+fn claim_with_wrong_amount_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    let mut claim = s.claims[1].clone();
+    claim.amount = 100;
+    s.client.claim(&claim, &Vec::from_array(&e, [s.leaves[0].clone()]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn claim_after_deadline_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    set_timestamp(&e, DEADLINE + 1);
+    s.client.claim(&s.claims[0], &Vec::from_array(&e, [s.leaves[1].clone()]));
+}
+
+#[test]
+fn sweep_after_deadline_returns_unclaimed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    s.client.claim(&s.claims[0], &Vec::from_array(&e, [s.leaves[1].clone()]));
+
+    set_timestamp(&e, DEADLINE + 1);
+    assert_eq!(s.client.sweep(), 50);
+    assert_eq!(s.token.balance(&s.admin), 50);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn sweep_before_deadline_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    set_timestamp(&e, DEADLINE);
+    s.client.sweep();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn deadline_in_the_past_fails() {
+    let e = Env::default();
+    set_timestamp(&e, DEADLINE);
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    e.register(MerkleAirdrop, (&admin, &token, BytesN::from_array(&e, &[0u8; 32]), DEADLINE));
+}
@@ -1,7 +1,14 @@
 //! Non-Fungible with Access Control Example Contract.
 //!
 //! Demonstrates how can Access Control be utilized.
+//!
+//! Accounts with the `metadata` role can set per-token URIs, change the base
+//! URI and freeze metadata.
+//!
+//! The collection can be capped with `max_supply` at deployment, and the
+//! admin can irreversibly finish minting.
 
+use nft_extensions::{metadata, supply};
 use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Vec};
 use stellar_access::access_control::{set_admin, AccessControl};
 use stellar_macros::{default_impl, has_any_role, has_role, only_admin, only_any_role, only_role};
@@ -12,9 +19,17 @@ pub struct ExampleContract;
 
 #[contractimpl]
 impl ExampleContract {
-    pub fn __constructor(e: &Env, admin: Address, base_uri: String, name: String, symbol: String) {
+    pub fn __constructor(
+        e: &Env,
+        admin: Address,
+        base_uri: String,
+        name: String,
+        symbol: String,
+        max_supply: Option<u32>,
+    ) {
         set_admin(e, &admin);
         Base::set_metadata(e, base_uri, name, symbol);
+        supply::set_max_supply(e, max_supply);
     }
 
     #[only_admin]
@@ -26,9 +41,29 @@ impl ExampleContract {
     // `require_auth()` in `Base::mint`.
     #[only_role(caller, "minter")]
     pub fn mint(e: &Env, caller: Address, to: Address, token_id: u32) {
+        supply::record_mint(e, 1);
         Base::mint(e, &to, token_id)
     }
 
+    /// Irreversibly disables minting.
+    #[only_admin]
+    pub fn finish_minting(e: &Env) {
+        supply::finish_minting(e);
+    }
+
+    /// Number of tokens minted so far, burned ones included.
+    pub fn total_minted(e: &Env) -> u32 {
+        supply::total_minted(e)
+    }
+
+    pub fn max_supply(e: &Env) -> Option<u32> {
+        supply::max_supply(e)
+    }
+
+    pub fn is_minting_finished(e: &Env) -> bool {
+        supply::is_minting_finished(e)
+    }
+
     // allows either minter or burner role, does not enforce `require_auth` in the
     // macro
     #[has_any_role(caller, ["minter", "burner"])]
@@ -42,12 +77,46 @@ impl ExampleContract {
     pub fn multi_role_auth_action(e: &Env, caller: Address) -> String {
         String::from_str(e, "multi_role_auth_action_success")
     }
+
+    #[only_role(operator, "metadata")]
+    pub fn set_token_uri(e: &Env, token_id: u32, uri: String, operator: Address) {
+        metadata::set_token_uri::<Base>(e, token_id, uri);
+    }
+
+    #[only_role(operator, "metadata")]
+    pub fn set_base_uri(e: &Env, base_uri: String, operator: Address) {
+        metadata::set_base_uri(e, base_uri);
+    }
+
+    /// Irreversibly freezes the metadata of every token.
+    #[only_role(operator, "metadata")]
+    pub fn freeze_metadata(e: &Env, operator: Address) {
+        metadata::freeze_metadata(e);
+    }
+
+    /// Irreversibly freezes the metadata of `token_id`.
+    #[only_role(operator, "metadata")]
+    pub fn freeze_token_metadata(e: &Env, token_id: u32, operator: Address) {
+        metadata::freeze_token_metadata::<Base>(e, token_id);
+    }
+
+    pub fn is_metadata_frozen(e: &Env) -> bool {
+        metadata::is_metadata_frozen(e)
+    }
+
+    pub fn is_token_metadata_frozen(e: &Env, token_id: u32) -> bool {
+        metadata::is_token_metadata_frozen(e, token_id)
+    }
 }
 
 #[default_impl]
 #[contractimpl]
 impl NonFungibleToken for ExampleContract {
     type ContractType = Base;
+
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        metadata::token_uri::<Base>(e, token_id)
+    }
 }
 
 // for this contract, the `burn*` functions are only meant to be called by
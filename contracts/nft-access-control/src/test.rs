@@ -9,7 +9,18 @@ use soroban_sdk::{
 use crate::contract::{ExampleContract, ExampleContractClient};
 
 fn create_client<'a>(e: &Env, admin: &Address) -> ExampleContractClient<'a> {
-    let address = e.register(ExampleContract, (admin,));
+    create_client_with_max_supply(e, admin, None)
+}
+
+fn create_client_with_max_supply<'a>(
+    e: &Env,
+    admin: &Address,
+    max_supply: Option<u32>,
+) -> ExampleContractClient<'a> {
+    let base_uri = String::from_str(e, "https://example.com/nft/");
+    let name = String::from_str(e, "My Token");
+    let symbol = String::from_str(e, "TKN");
+    let address = e.register(ExampleContract, (admin, base_uri, name, symbol, max_supply));
     ExampleContractClient::new(e, &address)
 }
 
@@ -541,3 +552,125 @@ fn outsiders_cannot_call_multi_role_auth_action() {
     // Outsider should not be able to call the function even with auth
     client.multi_role_auth_action(&accounts.outsider);
 }
+
+#[test]
+fn metadata_role_can_set_token_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let accounts = setup_roles(&e, &client, &admin);
+    let curator = Address::generate(&e);
+    client.grant_role(&admin, &curator, &Symbol::new(&e, "metadata"));
+
+    client.mint(&accounts.minter1, &accounts.outsider, &7);
+    let uri = String::from_str(&e, "ar://one-of-one");
+    client.set_token_uri(&7, &uri, &curator);
+    assert_eq!(client.token_uri(&7), uri);
+
+    client.set_base_uri(&String::from_str(&e, "ipfs://collection/"), &curator);
+    client.mint(&accounts.minter1, &accounts.outsider, &8);
+    assert_eq!(client.token_uri(&8), String::from_str(&e, "ipfs://collection/8"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn outsiders_cannot_set_base_uri() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let accounts = setup_roles(&e, &client, &admin);
+
+    client.set_base_uri(&String::from_str(&e, "ipfs://collection/"), &accounts.outsider);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+fn metadata_role_cannot_update_after_freeze() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let curator = Address::generate(&e);
+    client.grant_role(&admin, &curator, &Symbol::new(&e, "metadata"));
+
+    client.freeze_metadata(&curator);
+    assert!(client.is_metadata_frozen());
+    client.set_base_uri(&String::from_str(&e, "ipfs://collection/"), &curator);
+}
+
+#[test]
+fn total_minted_counts_burned_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client_with_max_supply(&e, &admin, Some(2));
+    let accounts = setup_roles(&e, &client, &admin);
+
+    client.mint(&accounts.minter1, &accounts.burner1, &1);
+    client.burn(&accounts.burner1, &1);
+    client.mint(&accounts.minter1, &accounts.outsider, &2);
+
+    assert_eq!(client.total_minted(), 2);
+    assert_eq!(client.max_supply(), Some(2));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+fn mint_beyond_max_supply_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client_with_max_supply(&e, &admin, Some(1));
+    let accounts = setup_roles(&e, &client, &admin);
+
+    client.mint(&accounts.minter1, &accounts.outsider, &1);
+    client.mint(&accounts.minter1, &accounts.outsider, &2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+fn zero_max_supply_fails() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    create_client_with_max_supply(&e, &admin, Some(0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn mint_after_finish_minting_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let accounts = setup_roles(&e, &client, &admin);
+
+    client.mint(&accounts.minter1, &accounts.outsider, &1);
+    client.finish_minting();
+    assert!(client.is_minting_finished());
+
+    client.mint(&accounts.minter1, &accounts.outsider, &2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn finish_minting_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    client.finish_minting();
+    client.finish_minting();
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn finish_minting_requires_admin_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    client.finish_minting();
+}
@@ -0,0 +1,493 @@
+//! # NFT Drop Contract Example
+//!
+//! An enumerable NFT collection sold to the public for a SEP-41 payment
+//! token, up to a fixed `max_supply`.
+//!
+//! The sale has up to two phases, each with its own price, per-wallet limit
+//! and ledger window:
+//!
+//! - **Presale** (optional) — restricted to accounts committed to as a Merkle
+//!   root. Each allowlist leaf can be redeemed once, for up to the presale
+//!   wallet limit.
+//! - **Public** — open to anyone until the wallet limit is reached.
+//!
+//! Tokens minted in either phase count toward the same per-wallet total.
+//! Payments are held by the contract until `withdraw` sends them to the
+//! payout address.
+//!
+//! The admin can set per-token URIs, change the base URI and freeze metadata.
+//!
+//! ## Hidden Reveal
+//!
+//! A drop can start hidden: every token reports the same placeholder URI
+//! until the admin reveals the final base URI, which is only possible once
+//! the drop sold out or its last sale phase ended. The constructor takes a
+//! commitment `sha256(base_uri || seed)`, where `base_uri` is the UTF-8
+//! string and `seed` a secret 32-byte value. `reveal(base_uri, seed)` checks
+//! the commitment, seeds Soroban's PRNG with `seed` and draws an offset in
+//! `0..max_supply`. Token `id` then resolves to `base_uri` followed by
+//! `(id + offset) % max_supply`, so anyone can reproduce the assignment from
+//! the published seed.
+//!
+//! The reveal only hides the assignment from buyers. The creator picks the
+//! seed before the sale, so they know the offset in advance and could try
+//! seeds until one suits them: fairness rests on trusting the creator.
+//!
+//! The base URI of a hidden drop is bound by the commitment: only `reveal`
+//! can set it, and it still can once the metadata is frozen.
+use nft_extensions::metadata::{self, BatchMetadataUpdateEvent};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, token,
+    Address, Bytes, BytesN, Env, String, Vec,
+};
+use stellar_contract_utils::{
+    crypto::sha256::Sha256,
+    merkle_distributor::{IndexableLeaf, MerkleDistributor},
+};
+use stellar_macros::default_impl;
+use stellar_tokens::non_fungible::{
+    enumerable::{Enumerable, NonFungibleEnumerable},
+    Base, NonFungibleToken,
+};
+
+type Distributor = MerkleDistributor<Sha256>;
+
+/// Maximum number of tokens minted in a single call.
+pub const MAX_MINT_PER_CALL: u32 = 20;
+
+/// Maximum length of the placeholder and revealed base URIs, in bytes.
+pub const MAX_URI_LEN: u32 = 200;
+
+/// Price, per-wallet limit and inclusive ledger window of a sale phase.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SalePhase {
+    pub price: i128,
+    pub wallet_limit: u32,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// Allowlisted presale, committed to as a Merkle root of `PresaleLeaf`s.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Presale {
+    pub root: BytesN<32>,
+    pub phase: SalePhase,
+}
+
+/// Placeholder shown until reveal, and the commitment
+/// `sha256(base_uri || seed)` the reveal must match.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HiddenReveal {
+    pub placeholder_uri: String,
+    pub commitment: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropConfig {
+    pub payment_token: Address,
+    pub payout: Address,
+    pub max_supply: u32,
+    pub public_sale: SalePhase,
+    pub presale: Option<Presale>,
+    pub hidden: Option<HiddenReveal>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PresaleLeaf {
+    pub index: u32,
+    pub account: Address,
+}
+
+impl IndexableLeaf for PresaleLeaf {
+    fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    TotalMinted,
+    Minted(Address),
+    /// Token id offset drawn at reveal.
+    RevealOffset,
+}
+
+#[contractevent]
+pub struct DropMintedEvent {
+    #[topic]
+    pub buyer: Address,
+    pub first_token_id: u32,
+    pub quantity: u32,
+    pub paid: i128,
+}
+
+#[contractevent]
+pub struct ProceedsWithdrawnEvent {
+    pub payout: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct PayoutUpdatedEvent {
+    pub payout: Address,
+}
+
+#[contractevent]
+pub struct RevealedEvent {
+    pub base_uri: String,
+    pub seed: BytesN<32>,
+    pub offset: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DropError {
+    InvalidConfig = 1,
+    InvalidQuantity = 2,
+    SaleNotActive = 3,
+    ExceededMaxSupply = 4,
+    ExceededWalletLimit = 5,
+    PresaleNotConfigured = 6,
+    NothingToWithdraw = 7,
+    NotHidden = 8,
+    AlreadyRevealed = 9,
+    InvalidReveal = 10,
+    BaseUriCommitted = 11,
+    SaleNotOver = 12,
+}
+
+#[contract]
+pub struct NFTDrop;
+
+#[contractimpl]
+impl NFTDrop {
+    pub fn __constructor(
+        e: &Env,
+        admin: Address,
+        base_uri: String,
+        name: String,
+        symbol: String,
+        config: DropConfig,
+    ) {
+        validate_config(e, &config);
+
+        Base::set_metadata(e, base_uri, name, symbol);
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        if let Some(presale) = &config.presale {
+            Distributor::set_root(e, presale.root.clone());
+        }
+        e.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Mints `quantity` tokens to `buyer` during the public sale, charging
+    /// the public price for each.
+    ///
+    /// # Returns
+    /// ID of the first minted token
+    pub fn mint(e: &Env, buyer: Address, quantity: u32) -> u32 {
+        buyer.require_auth();
+
+        let config = get_config(e);
+        check_phase(e, &config.public_sale);
+        check_wallet_limit(e, &buyer, quantity, config.public_sale.wallet_limit);
+
+        mint_paid(e, &config, &buyer, quantity, config.public_sale.price)
+    }
+
+    /// Mints `quantity` tokens to the allowlisted `leaf.account` during the
+    /// presale, charging the presale price for each. Each leaf can only be
+    /// redeemed once.
+    ///
+    /// # Returns
+    /// ID of the first minted token
+    pub fn presale_mint(e: &Env, leaf: PresaleLeaf, proof: Vec<BytesN<32>>, quantity: u32) -> u32 {
+        let buyer = leaf.account.clone();
+        buyer.require_auth();
+
+        let config = get_config(e);
+        let presale = config
+            .presale
+            .clone()
+            .unwrap_or_else(|| panic_with_error!(e, DropError::PresaleNotConfigured));
+        check_phase(e, &presale.phase);
+        check_wallet_limit(e, &buyer, quantity, presale.phase.wallet_limit);
+
+        // Verify merkle proof and mark the leaf as redeemed
+        Distributor::verify_and_set_claimed(e, leaf, proof);
+
+        mint_paid(e, &config, &buyer, quantity, presale.phase.price)
+    }
+
+    /// Sends every collected payment to the payout address.
+    ///
+    /// # Returns
+    /// Amount withdrawn
+    pub fn withdraw(e: &Env) -> i128 {
+        get_admin(e).require_auth();
+
+        let config = get_config(e);
+        let token = token::Client::new(e, &config.payment_token);
+        let amount = token.balance(&e.current_contract_address());
+        if amount <= 0 {
+            panic_with_error!(e, DropError::NothingToWithdraw);
+        }
+        token.transfer(&e.current_contract_address(), &config.payout, &amount);
+
+        ProceedsWithdrawnEvent { payout: config.payout, amount }.publish(e);
+        amount
+    }
+
+    pub fn set_payout(e: &Env, payout: Address) {
+        get_admin(e).require_auth();
+
+        let mut config = get_config(e);
+        config.payout = payout.clone();
+        e.storage().instance().set(&DataKey::Config, &config);
+
+        PayoutUpdatedEvent { payout }.publish(e);
+    }
+
+    pub fn config(e: &Env) -> DropConfig {
+        get_config(e)
+    }
+
+    pub fn admin(e: &Env) -> Address {
+        get_admin(e)
+    }
+
+    pub fn total_minted(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::TotalMinted).unwrap_or(0)
+    }
+
+    /// Number of tokens `account` bought across both phases.
+    pub fn minted_by(e: &Env, account: Address) -> u32 {
+        e.storage().persistent().get(&DataKey::Minted(account)).unwrap_or(0)
+    }
+
+    pub fn is_presale_redeemed(e: &Env, index: u32) -> bool {
+        Distributor::is_claimed(e, index)
+    }
+
+    /// Reveals a hidden drop once it sold out or the sale ended. `base_uri`
+    /// and `seed` must match the commitment given at construction.
+    pub fn reveal(e: &Env, base_uri: String, seed: BytesN<32>) {
+        get_admin(e).require_auth();
+
+        let config = get_config(e);
+        let hidden = config.hidden.unwrap_or_else(|| panic_with_error!(e, DropError::NotHidden));
+        if Self::reveal_offset(e).is_some() {
+            panic_with_error!(e, DropError::AlreadyRevealed);
+        }
+        // Revealing while tokens can still be minted would let buyers pick them
+        if Self::total_minted(e) < config.max_supply && !sale_ended(e, &config) {
+            panic_with_error!(e, DropError::SaleNotOver);
+        }
+        if base_uri.len() == 0 || base_uri.len() > MAX_URI_LEN {
+            panic_with_error!(e, DropError::InvalidReveal);
+        }
+
+        let mut preimage = base_uri.to_bytes();
+        preimage.append(&Bytes::from(seed.clone()));
+        if e.crypto().sha256(&preimage).to_bytes() != hidden.commitment {
+            panic_with_error!(e, DropError::InvalidReveal);
+        }
+
+        // Deterministic in the seed, so anyone can recompute the offset. The
+        // creator chose the seed and knew the offset all along
+        e.prng().seed(Bytes::from(seed.clone()));
+        let offset = e.prng().gen_range::<u64>(0..config.max_supply as u64) as u32;
+        e.storage().instance().set(&DataKey::RevealOffset, &offset);
+
+        // Written directly so a metadata freeze cannot block the committed URI
+        Base::set_metadata(e, base_uri.clone(), Base::name(e), Base::symbol(e));
+        BatchMetadataUpdateEvent { from_token_id: 0, to_token_id: u32::MAX }.publish(e);
+
+        RevealedEvent { base_uri, seed, offset }.publish(e);
+    }
+
+    pub fn is_revealed(e: &Env) -> bool {
+        get_config(e).hidden.is_none() || Self::reveal_offset(e).is_some()
+    }
+
+    /// Token id offset drawn at reveal, if the drop was revealed.
+    pub fn reveal_offset(e: &Env) -> Option<u32> {
+        e.storage().instance().get(&DataKey::RevealOffset)
+    }
+
+    pub fn set_token_uri(e: &Env, token_id: u32, uri: String) {
+        get_admin(e).require_auth();
+        metadata::set_token_uri::<Enumerable>(e, token_id, uri);
+    }
+
+    /// Fails for hidden drops, whose base URI is set by `reveal`.
+    pub fn set_base_uri(e: &Env, base_uri: String) {
+        get_admin(e).require_auth();
+        if get_config(e).hidden.is_some() {
+            panic_with_error!(e, DropError::BaseUriCommitted);
+        }
+        metadata::set_base_uri(e, base_uri);
+    }
+
+    /// Irreversibly freezes the metadata of every token.
+    pub fn freeze_metadata(e: &Env) {
+        get_admin(e).require_auth();
+        metadata::freeze_metadata(e);
+    }
+
+    /// Irreversibly freezes the metadata of `token_id`.
+    pub fn freeze_token_metadata(e: &Env, token_id: u32) {
+        get_admin(e).require_auth();
+        metadata::freeze_token_metadata::<Enumerable>(e, token_id);
+    }
+
+    pub fn is_metadata_frozen(e: &Env) -> bool {
+        metadata::is_metadata_frozen(e)
+    }
+
+    pub fn is_token_metadata_frozen(e: &Env, token_id: u32) -> bool {
+        metadata::is_token_metadata_frozen(e, token_id)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for NFTDrop {
+    type ContractType = Enumerable;
+
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        let config = get_config(e);
+        let Some(hidden) = config.hidden else {
+            return metadata::token_uri::<Enumerable>(e, token_id);
+        };
+
+        // Panics if the token does not exist
+        Base::owner_of(e, token_id);
+        match NFTDrop::reveal_offset(e) {
+            None => hidden.placeholder_uri,
+            Some(offset) => metadata::get_token_uri(e, token_id).unwrap_or_else(|| {
+                let shifted_id = (token_id as u64 + offset as u64) % config.max_supply as u64;
+                compose_uri(e, &Base::base_uri(e), shifted_id as u32)
+            }),
+        }
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleEnumerable for NFTDrop {}
+
+fn validate_config(e: &Env, config: &DropConfig) {
+    if config.max_supply == 0 {
+        panic_with_error!(e, DropError::InvalidConfig);
+    }
+    validate_phase(e, &config.public_sale);
+    if let Some(presale) = &config.presale {
+        validate_phase(e, &presale.phase);
+    }
+    if let Some(hidden) = &config.hidden {
+        validate_hidden(e, hidden);
+    }
+}
+
+fn validate_hidden(e: &Env, hidden: &HiddenReveal) {
+    let len = hidden.placeholder_uri.len();
+    if len == 0 || len > MAX_URI_LEN {
+        panic_with_error!(e, DropError::InvalidConfig);
+    }
+}
+
+fn validate_phase(e: &Env, phase: &SalePhase) {
+    if phase.price < 0 || phase.wallet_limit == 0 || phase.start_ledger > phase.end_ledger {
+        panic_with_error!(e, DropError::InvalidConfig);
+    }
+}
+
+fn sale_ended(e: &Env, config: &DropConfig) -> bool {
+    let presale_end = config.presale.as_ref().map_or(0, |presale| presale.phase.end_ledger);
+    e.ledger().sequence() > config.public_sale.end_ledger.max(presale_end)
+}
+
+fn check_phase(e: &Env, phase: &SalePhase) {
+    let ledger = e.ledger().sequence();
+    if ledger < phase.start_ledger || ledger > phase.end_ledger {
+        panic_with_error!(e, DropError::SaleNotActive);
+    }
+}
+
+fn check_wallet_limit(e: &Env, buyer: &Address, quantity: u32, wallet_limit: u32) {
+    if quantity == 0 || quantity > MAX_MINT_PER_CALL {
+        panic_with_error!(e, DropError::InvalidQuantity);
+    }
+    let minted = NFTDrop::minted_by(e, buyer.clone());
+    if minted + quantity > wallet_limit {
+        panic_with_error!(e, DropError::ExceededWalletLimit);
+    }
+}
+
+/// Collects `price * quantity` from `buyer` and mints the tokens.
+fn mint_paid(e: &Env, config: &DropConfig, buyer: &Address, quantity: u32, price: i128) -> u32 {
+    let total_minted = NFTDrop::total_minted(e);
+    if total_minted + quantity > config.max_supply {
+        panic_with_error!(e, DropError::ExceededMaxSupply);
+    }
+
+    let paid = price * quantity as i128;
+    if paid > 0 {
+        token::Client::new(e, &config.payment_token).transfer(
+            buyer,
+            &e.current_contract_address(),
+            &paid,
+        );
+    }
+
+    let first_token_id = Enumerable::sequential_mint(e, buyer);
+    for _ in 1..quantity {
+        Enumerable::sequential_mint(e, buyer);
+    }
+
+    e.storage().instance().set(&DataKey::TotalMinted, &(total_minted + quantity));
+    let minted = NFTDrop::minted_by(e, buyer.clone());
+    e.storage().persistent().set(&DataKey::Minted(buyer.clone()), &(minted + quantity));
+
+    DropMintedEvent { buyer: buyer.clone(), first_token_id, quantity, paid }.publish(e);
+    first_token_id
+}
+
+/// Appends the decimal `token_id` to `base_uri`.
+fn compose_uri(e: &Env, base_uri: &String, token_id: u32) -> String {
+    let len = base_uri.len() as usize;
+    let mut uri = [0u8; MAX_URI_LEN as usize + 10];
+    base_uri.copy_into_slice(&mut uri[..len]);
+
+    let mut digits = [0u8; 10];
+    let mut start = digits.len();
+    let mut n = token_id;
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+
+    let end = len + digits.len() - start;
+    uri[len..end].copy_from_slice(&digits[start..]);
+    String::from_bytes(e, &uri[..end])
+}
+
+fn get_config(e: &Env) -> DropConfig {
+    e.storage().instance().get(&DataKey::Config).expect("config should be set")
+}
+
+fn get_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).expect("admin should be set")
+}
@@ -0,0 +1,6 @@
+#![no_std]
+#![allow(dead_code)]
+
+pub mod contract;
+#[cfg(test)]
+mod test;
@@ -0,0 +1,563 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String, Vec,
+};
+use stellar_contract_utils::crypto::{
+    hashable::commutative_hash_pair, hasher::Hasher, sha256::Sha256,
+};
+
+use crate::contract::{
+    DropConfig, HiddenReveal, NFTDrop, NFTDropClient, Presale, PresaleLeaf, SalePhase,
+};
+
+const PRICE: i128 = 100;
+const PRESALE_PRICE: i128 = 60;
+const PRESALE_START: u32 = 100;
+const PRESALE_END: u32 = 199;
+const PUBLIC_START: u32 = 200;
+const PUBLIC_END: u32 = 299;
+
+fn hash_leaf(e: &Env, leaf: &PresaleLeaf) -> BytesN<32> {
+    let mut hasher = Sha256::new(e);
+    hasher.update(leaf.clone().to_xdr(e));
+    hasher.finalize()
+}
+
+struct Setup<'a> {
+    client: NFTDropClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    payout: Address,
+    leaves: [PresaleLeaf; 2],
+    hashes: [BytesN<32>; 2],
+}
+
+// Collection of 5 tokens with a two-leaf presale allowlist
+fn setup<'a>(e: &Env) -> Setup<'a> {
+    setup_with_hidden(e, None)
+}
+
+fn setup_with_hidden<'a>(e: &Env, hidden: Option<HiddenReveal>) -> Setup<'a> {
+    let admin = Address::generate(e);
+    let payout = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(e, &sac.address());
+
+    let leaves = [
+        PresaleLeaf { index: 0, account: Address::generate(e) },
+        PresaleLeaf { index: 1, account: Address::generate(e) },
+    ];
+    let hashes = [hash_leaf(e, &leaves[0]), hash_leaf(e, &leaves[1])];
+    let root = commutative_hash_pair(&hashes[0], &hashes[1], Sha256::new(e));
+
+    let config = DropConfig {
+        payment_token: sac.address(),
+        payout: payout.clone(),
+        max_supply: 5,
+        public_sale: SalePhase {
+            price: PRICE,
+            wallet_limit: 3,
+            start_ledger: PUBLIC_START,
+            end_ledger: PUBLIC_END,
+        },
+        presale: Some(Presale {
+            root,
+            phase: SalePhase {
+                price: PRESALE_PRICE,
+                wallet_limit: 2,
+                start_ledger: PRESALE_START,
+                end_ledger: PRESALE_END,
+            },
+        }),
+        hidden,
+    };
+    let address = e.register(
+        NFTDrop,
+        (
+            &admin,
+            String::from_str(e, "https://example.com/drop/"),
+            String::from_str(e, "Drop"),
+            String::from_str(e, "DROP"),
+            config,
+        ),
+    );
+
+    Setup { client: NFTDropClient::new(e, &address), token, admin, payout, leaves, hashes }
+}
+
+fn fund(e: &Env, s: &Setup, account: &Address, amount: i128) {
+    token::StellarAssetClient::new(e, &s.token.address).mint(account, &amount);
+}
+
+fn set_ledger(e: &Env, sequence: u32) {
+    e.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+#[test]
+fn public_mint_charges_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    let first = s.client.mint(&buyer, &2);
+
+    assert_eq!(first, 0);
+    assert_eq!(s.client.balance(&buyer), 2);
+    assert_eq!(s.client.owner_of(&1), buyer);
+    assert_eq!(s.client.total_minted(), 2);
+    assert_eq!(s.client.minted_by(&buyer), 2);
+    assert_eq!(s.token.balance(&buyer), 1_000 - 2 * PRICE);
+    assert_eq!(s.token.balance(&s.client.address), 2 * PRICE);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn public_mint_before_start_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START - 1);
+    s.client.mint(&buyer, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn public_mint_after_end_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_END + 1);
+    s.client.mint(&buyer, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn public_mint_over_wallet_limit_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &2);
+    s.client.mint(&buyer, &2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn public_mint_over_max_supply_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let first_buyer = Address::generate(&e);
+    let second_buyer = Address::generate(&e);
+    fund(&e, &s, &first_buyer, 1_000);
+    fund(&e, &s, &second_buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&first_buyer, &3);
+    s.client.mint(&second_buyer, &3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn public_mint_zero_quantity_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &0);
+}
+
+#[test]
+#[should_panic]
+fn public_mint_without_funds_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &1);
+}
+
+#[test]
+fn presale_mint_charges_presale_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = s.leaves[0].account.clone();
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PRESALE_START);
+    let proof = Vec::from_array(&e, [s.hashes[1].clone()]);
+    s.client.presale_mint(&s.leaves[0], &proof, &2);
+
+    assert!(s.client.is_presale_redeemed(&0));
+    assert_eq!(s.client.balance(&buyer), 2);
+    assert_eq!(s.token.balance(&s.client.address), 2 * PRESALE_PRICE);
+
+    // Presale mints count toward the public wallet limit
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &1);
+    assert_eq!(s.client.minted_by(&buyer), 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1301)")] // MerkleDistributor IndexAlreadyClaimed
+fn presale_mint_redeemed_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = s.leaves[0].account.clone();
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PRESALE_START);
+    let proof = Vec::from_array(&e, [s.hashes[1].clone()]);
+    s.client.presale_mint(&s.leaves[0], &proof, &1);
+    s.client.presale_mint(&s.leaves[0], &proof, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1302)")] // MerkleDistributor InvalidProof
+fn presale_mint_not_allowlisted_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PRESALE_START);
+    let leaf = PresaleLeaf { index: 0, account: buyer };
+    let proof = Vec::from_array(&e, [s.hashes[1].clone()]);
+    s.client.presale_mint(&leaf, &proof, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn presale_mint_over_presale_limit_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = s.leaves[1].account.clone();
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PRESALE_START);
+    let proof = Vec::from_array(&e, [s.hashes[0].clone()]);
+    s.client.presale_mint(&s.leaves[1], &proof, &3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn presale_mint_after_presale_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = s.leaves[0].account.clone();
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    let proof = Vec::from_array(&e, [s.hashes[1].clone()]);
+    s.client.presale_mint(&s.leaves[0], &proof, &1);
+}
+
+#[test]
+fn withdraw_sends_proceeds_to_payout() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &3);
+
+    assert_eq!(s.client.withdraw(), 3 * PRICE);
+    assert_eq!(s.token.balance(&s.payout), 3 * PRICE);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn set_payout_redirects_withdrawals() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    let new_payout = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &1);
+    s.client.set_payout(&new_payout);
+    s.client.withdraw();
+
+    assert_eq!(s.client.config().payout, new_payout);
+    assert_eq!(s.token.balance(&new_payout), PRICE);
+    assert_eq!(s.token.balance(&s.payout), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn withdraw_without_proceeds_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+
+    s.client.withdraw();
+}
+
+#[test]
+#[should_panic]
+fn withdraw_requires_admin_auth() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    s.client.withdraw();
+}
+
+#[test]
+fn admin_is_set() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    assert_eq!(s.client.admin(), s.admin);
+}
+
+#[test]
+fn admin_sets_token_uri_and_freezes() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &1);
+    assert_eq!(s.client.token_uri(&0), String::from_str(&e, "https://example.com/drop/0"));
+
+    let uri = String::from_str(&e, "ar://drop-0");
+    s.client.set_token_uri(&0, &uri);
+    assert_eq!(s.client.token_uri(&0), uri);
+
+    s.client.freeze_metadata();
+    assert!(s.client.is_metadata_frozen());
+}
+
+// ==================== Hidden Reveal Tests ====================
+
+const REVEAL_URI: &str = "ipfs://revealed/";
+const PLACEHOLDER_URI: &str = "ipfs://placeholder.json";
+
+fn commitment(e: &Env, base_uri: &str, seed: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = String::from_str(e, base_uri).to_bytes();
+    preimage.append(&Bytes::from(seed.clone()));
+    e.crypto().sha256(&preimage).to_bytes()
+}
+
+fn setup_hidden<'a>(e: &Env, seed: &BytesN<32>) -> Setup<'a> {
+    setup_with_hidden(
+        e,
+        Some(HiddenReveal {
+            placeholder_uri: String::from_str(e, PLACEHOLDER_URI),
+            commitment: commitment(e, REVEAL_URI, seed),
+        }),
+    )
+}
+
+fn mint_out(e: &Env, s: &Setup) {
+    let first_buyer = Address::generate(e);
+    let second_buyer = Address::generate(e);
+    fund(e, s, &first_buyer, 1_000);
+    fund(e, s, &second_buyer, 1_000);
+
+    set_ledger(e, PUBLIC_START);
+    s.client.mint(&first_buyer, &3);
+    s.client.mint(&second_buyer, &2);
+}
+
+#[test]
+fn hidden_drop_shows_placeholder_until_reveal() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    assert!(!s.client.is_revealed());
+    assert_eq!(s.client.reveal_offset(), None);
+    for token_id in 0..5 {
+        assert_eq!(s.client.token_uri(&token_id), String::from_str(&e, PLACEHOLDER_URI));
+    }
+}
+
+#[test]
+fn reveal_shifts_token_ids_by_offset() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+
+    // The offset is reproducible from the published seed
+    let expected_offset = e.as_contract(&s.client.address, || {
+        e.prng().seed(Bytes::from(seed.clone()));
+        e.prng().gen_range::<u64>(0..5) as u32
+    });
+    assert!(s.client.is_revealed());
+    assert_eq!(s.client.reveal_offset(), Some(expected_offset));
+
+    for token_id in 0..5u32 {
+        let shifted_id = (token_id + expected_offset) % 5;
+        let expected = std::format!("{REVEAL_URI}{shifted_id}");
+        assert_eq!(s.client.token_uri(&token_id), String::from_str(&e, &expected));
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn reveal_with_wrong_seed_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &BytesN::from_array(&e, &[8u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn reveal_with_wrong_base_uri_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    s.client.reveal(&String::from_str(&e, "ipfs://other/"), &seed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn reveal_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn reveal_before_sold_out_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &1);
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+}
+
+#[test]
+fn reveal_after_sale_end_without_sold_out() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    let buyer = Address::generate(&e);
+    fund(&e, &s, &buyer, 1_000);
+
+    set_ledger(&e, PUBLIC_START);
+    s.client.mint(&buyer, &1);
+
+    set_ledger(&e, PUBLIC_END + 1);
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+    assert!(s.client.is_revealed());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn reveal_without_hidden_mode_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let s = setup(&e);
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+}
+
+#[test]
+#[should_panic]
+fn reveal_requires_admin_auth() {
+    let e = Env::default();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+}
+
+#[test]
+fn reveal_after_metadata_frozen() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    // Freezing first still lets the committed base URI through
+    s.client.freeze_metadata();
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+
+    let offset = s.client.reveal_offset().unwrap();
+    let expected = std::format!("{REVEAL_URI}{}", offset % 5);
+    assert_eq!(s.client.token_uri(&0), String::from_str(&e, &expected));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn set_base_uri_before_reveal_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+
+    s.client.set_base_uri(&String::from_str(&e, "ipfs://other/"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn set_base_uri_after_reveal_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let seed = BytesN::from_array(&e, &[7u8; 32]);
+    let s = setup_hidden(&e, &seed);
+    mint_out(&e, &s);
+
+    s.client.reveal(&String::from_str(&e, REVEAL_URI), &seed);
+    s.client.set_base_uri(&String::from_str(&e, "ipfs://other/"));
+}
@@ -3,8 +3,24 @@
 //! Demonstrates an example usage of the Enumerable extension, allowing for
 //! enumeration of all the token IDs in the contract as well as all the token
 //! IDs owned by each account.
+//!
+//! The owner can set per-token URIs, change the base URI and freeze metadata.
+//!
+//! Tokens are rentable (ERC-4907): their owner, or an approved account, can
+//! grant a time-limited user role that is cleared on transfer and burn.
+//!
+//! `batch_mint` mints one token to each of up to `MAX_BATCH_SIZE` recipients
+//! in a single call. Every token still gets its own ownership and index
+//! entries, as enumeration requires.
+//!
+//! The collection can be capped with `max_supply` at deployment, and the
+//! owner can irreversibly finish minting.
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
+use nft_extensions::{metadata, rental, supply};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, Env, String,
+    Vec,
+};
 use stellar_macros::default_impl;
 use stellar_tokens::non_fungible::{
     burnable::NonFungibleBurnable,
@@ -12,39 +28,164 @@ use stellar_tokens::non_fungible::{
     Base, NonFungibleToken,
 };
 
+/// Maximum number of recipients of a `batch_mint` call.
+pub const MAX_BATCH_SIZE: u32 = 100;
+
 #[contracttype]
 pub enum DataKey {
     Owner,
 }
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    InvalidBatchSize = 1,
+}
+
 #[contract]
 pub struct ExampleContract;
 
 #[contractimpl]
 impl ExampleContract {
-    pub fn __constructor(e: &Env, owner: Address, base_uri: String, name: String, symbol: String) {
+    pub fn __constructor(
+        e: &Env,
+        owner: Address,
+        base_uri: String,
+        name: String,
+        symbol: String,
+        max_supply: Option<u32>,
+    ) {
         e.storage().instance().set(&DataKey::Owner, &owner);
         Base::set_metadata(e, base_uri, name, symbol);
+        supply::set_max_supply(e, max_supply);
     }
 
     pub fn mint(e: &Env, to: Address) -> u32 {
-        let owner: Address =
-            e.storage().instance().get(&DataKey::Owner).expect("owner should be set");
-        owner.require_auth();
+        get_owner(e).require_auth();
+        supply::record_mint(e, 1);
         Enumerable::sequential_mint(e, &to)
     }
+
+    /// Mints one token to each address in `to`, in order.
+    ///
+    /// # Returns
+    /// ID of the first minted token; the rest follow consecutively
+    pub fn batch_mint(e: &Env, to: Vec<Address>) -> u32 {
+        get_owner(e).require_auth();
+        if to.is_empty() || to.len() > MAX_BATCH_SIZE {
+            panic_with_error!(e, ExampleContractError::InvalidBatchSize);
+        }
+        supply::record_mint(e, to.len());
+
+        let first_id = Enumerable::sequential_mint(e, &to.get_unchecked(0));
+        for recipient in to.iter().skip(1) {
+            Enumerable::sequential_mint(e, &recipient);
+        }
+        first_id
+    }
+
+    /// Irreversibly disables minting.
+    pub fn finish_minting(e: &Env) {
+        get_owner(e).require_auth();
+        supply::finish_minting(e);
+    }
+
+    /// Number of tokens minted so far, burned ones included.
+    pub fn total_minted(e: &Env) -> u32 {
+        supply::total_minted(e)
+    }
+
+    pub fn max_supply(e: &Env) -> Option<u32> {
+        supply::max_supply(e)
+    }
+
+    pub fn is_minting_finished(e: &Env) -> bool {
+        supply::is_minting_finished(e)
+    }
+
+    pub fn set_token_uri(e: &Env, token_id: u32, uri: String) {
+        get_owner(e).require_auth();
+        metadata::set_token_uri::<Enumerable>(e, token_id, uri);
+    }
+
+    pub fn set_base_uri(e: &Env, base_uri: String) {
+        get_owner(e).require_auth();
+        metadata::set_base_uri(e, base_uri);
+    }
+
+    /// Irreversibly freezes the metadata of every token.
+    pub fn freeze_metadata(e: &Env) {
+        get_owner(e).require_auth();
+        metadata::freeze_metadata(e);
+    }
+
+    /// Irreversibly freezes the metadata of `token_id`.
+    pub fn freeze_token_metadata(e: &Env, token_id: u32) {
+        get_owner(e).require_auth();
+        metadata::freeze_token_metadata::<Enumerable>(e, token_id);
+    }
+
+    pub fn is_metadata_frozen(e: &Env) -> bool {
+        metadata::is_metadata_frozen(e)
+    }
+
+    pub fn is_token_metadata_frozen(e: &Env, token_id: u32) -> bool {
+        metadata::is_token_metadata_frozen(e, token_id)
+    }
+
+    /// Grants `user` the user role of `token_id` until `expires_ledger`
+    /// (inclusive). `caller` must own the token or be approved for it.
+    pub fn set_user(e: &Env, caller: Address, token_id: u32, user: Address, expires_ledger: u32) {
+        rental::set_user::<Enumerable>(e, &caller, token_id, &user, expires_ledger);
+    }
+
+    pub fn user_of(e: &Env, token_id: u32) -> Option<Address> {
+        rental::user_of(e, token_id)
+    }
+
+    pub fn user_expires(e: &Env, token_id: u32) -> Option<u32> {
+        rental::user_expires(e, token_id)
+    }
 }
 
 #[default_impl]
 #[contractimpl]
 impl NonFungibleToken for ExampleContract {
     type ContractType = Enumerable;
+
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        Enumerable::transfer(e, &from, &to, token_id);
+        rental::clear_user(e, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Enumerable::transfer_from(e, &spender, &from, &to, token_id);
+        rental::clear_user(e, token_id);
+    }
+
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        metadata::token_uri::<Enumerable>(e, token_id)
+    }
 }
 
 #[default_impl]
 #[contractimpl]
 impl NonFungibleEnumerable for ExampleContract {}
 
-#[default_impl]
 #[contractimpl]
-impl NonFungibleBurnable for ExampleContract {}
+impl NonFungibleBurnable for ExampleContract {
+    fn burn(e: &Env, from: Address, token_id: u32) {
+        Enumerable::burn(e, &from, token_id);
+        rental::clear_user(e, token_id);
+    }
+
+    fn burn_from(e: &Env, spender: Address, from: Address, token_id: u32) {
+        Enumerable::burn_from(e, &spender, &from, token_id);
+        rental::clear_user(e, token_id);
+    }
+}
+
+fn get_owner(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Owner).expect("owner should be set")
+}
@@ -1,11 +1,25 @@
 extern crate std;
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String, Vec,
+};
 
-use crate::contract::{ExampleContract, ExampleContractClient};
+use crate::contract::{ExampleContract, ExampleContractClient, MAX_BATCH_SIZE};
 
 fn create_client<'a>(e: &Env, owner: &Address) -> ExampleContractClient<'a> {
-    let address = e.register(ExampleContract, (owner,));
+    create_client_with_max_supply(e, owner, None)
+}
+
+fn create_client_with_max_supply<'a>(
+    e: &Env,
+    owner: &Address,
+    max_supply: Option<u32>,
+) -> ExampleContractClient<'a> {
+    let base_uri = String::from_str(e, "https://example.com/nft/");
+    let name = String::from_str(e, "My Token");
+    let symbol = String::from_str(e, "TKN");
+    let address = e.register(ExampleContract, (owner, base_uri, name, symbol, max_supply));
     ExampleContractClient::new(e, &address)
 }
 
@@ -40,3 +54,403 @@ fn enumerable_burn_works() {
     assert_eq!(client.balance(&owner), 1);
     assert_eq!(client.get_owner_token_id(&owner, &0), 1);
 }
+
+#[test]
+fn token_uri_defaults_to_base_uri() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+    assert_eq!(client.token_uri(&0), String::from_str(&e, "https://example.com/nft/0"));
+}
+
+#[test]
+fn set_token_uri_overrides_base_uri() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+    client.mint(&owner);
+
+    let uri = String::from_str(&e, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylq/0.json");
+    client.set_token_uri(&0, &uri);
+    assert_eq!(client.token_uri(&0), uri);
+    assert_eq!(client.token_uri(&1), String::from_str(&e, "https://example.com/nft/1"));
+}
+
+#[test]
+fn set_base_uri_updates_derived_uris() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.set_base_uri(&String::from_str(&e, "ar://collection/"));
+    assert_eq!(client.token_uri(&0), String::from_str(&e, "ar://collection/0"));
+    assert_eq!(client.name(), String::from_str(&e, "My Token"));
+    assert_eq!(client.symbol(), String::from_str(&e, "TKN"));
+}
+
+#[test]
+#[should_panic]
+fn set_token_uri_nonexistent_token_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.set_token_uri(&0, &String::from_str(&e, "ar://token"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+fn set_token_uri_empty_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+    client.set_token_uri(&0, &String::from_str(&e, ""));
+}
+
+#[test]
+#[should_panic]
+fn set_base_uri_requires_owner_auth() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    client.set_base_uri(&String::from_str(&e, "ar://collection/"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+fn frozen_token_uri_cannot_change() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+    client.mint(&owner);
+
+    client.freeze_token_metadata(&0);
+    assert!(client.is_token_metadata_frozen(&0));
+    assert!(!client.is_token_metadata_frozen(&1));
+
+    // Other tokens stay mutable
+    client.set_token_uri(&1, &String::from_str(&e, "ar://token-1"));
+    client.set_token_uri(&0, &String::from_str(&e, "ar://token-0"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+fn frozen_metadata_blocks_base_uri_change() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.freeze_metadata();
+    assert!(client.is_metadata_frozen());
+    assert!(client.is_token_metadata_frozen(&0));
+    client.set_base_uri(&String::from_str(&e, "ar://collection/"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+fn frozen_metadata_blocks_token_uri_change() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.freeze_metadata();
+    client.set_token_uri(&0, &String::from_str(&e, "ar://token-0"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+fn freeze_metadata_twice_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.freeze_metadata();
+    client.freeze_metadata();
+}
+
+// ==================== Rental Tests ====================
+
+fn set_ledger(e: &Env, sequence: u32) {
+    e.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+#[test]
+fn owner_can_set_user_until_expiry() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    set_ledger(&e, 100);
+    client.set_user(&owner, &0, &renter, &150);
+    assert_eq!(client.user_of(&0), Some(renter.clone()));
+    assert_eq!(client.user_expires(&0), Some(150));
+
+    // Ownership does not change
+    assert_eq!(client.owner_of(&0), owner);
+
+    set_ledger(&e, 150);
+    assert_eq!(client.user_of(&0), Some(renter));
+
+    set_ledger(&e, 151);
+    assert_eq!(client.user_of(&0), None);
+    assert_eq!(client.user_expires(&0), None);
+}
+
+#[test]
+fn approved_account_can_set_user() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let marketplace = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+    client.mint(&owner);
+
+    client.approve(&owner, &operator, &0, &1_000);
+    client.set_user(&operator, &0, &renter, &500);
+    assert_eq!(client.user_of(&0), Some(renter.clone()));
+
+    client.approve_for_all(&owner, &marketplace, &1_000);
+    client.set_user(&marketplace, &1, &renter, &500);
+    assert_eq!(client.user_of(&1), Some(renter));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #60)")]
+fn outsider_cannot_set_user() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let outsider = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.set_user(&outsider, &0, &outsider, &500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #61)")]
+fn set_user_with_past_expiry_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    set_ledger(&e, 100);
+    client.set_user(&owner, &0, &renter, &99);
+}
+
+#[test]
+fn transfer_clears_user() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.set_user(&owner, &0, &renter, &500);
+    client.transfer(&owner, &recipient, &0);
+
+    assert_eq!(client.user_of(&0), None);
+    assert_eq!(client.get_owner_token_id(&recipient, &0), 0);
+}
+
+#[test]
+fn transfer_from_clears_user() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.set_user(&owner, &0, &renter, &500);
+    client.approve(&owner, &operator, &0, &1_000);
+    client.transfer_from(&operator, &owner, &operator, &0);
+
+    assert_eq!(client.user_of(&0), None);
+}
+
+#[test]
+fn burn_clears_user() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let renter = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    client.set_user(&owner, &0, &renter, &500);
+    client.burn(&owner, &0);
+
+    assert_eq!(client.user_of(&0), None);
+}
+
+#[test]
+fn batch_mint_mints_to_each_recipient() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    client.mint(&owner);
+
+    let first_id = client.batch_mint(&vec![&e, alice.clone(), bob.clone(), alice.clone()]);
+
+    assert_eq!(first_id, 1);
+    assert_eq!(client.owner_of(&1), alice);
+    assert_eq!(client.owner_of(&2), bob);
+    assert_eq!(client.owner_of(&3), alice);
+    assert_eq!(client.total_supply(), 4);
+    assert_eq!(client.balance(&alice), 2);
+    assert_eq!(client.get_owner_token_id(&alice, &1), 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn batch_mint_empty_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+
+    client.batch_mint(&Vec::new(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn batch_mint_too_large_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+
+    client.batch_mint(&recipients(&e, MAX_BATCH_SIZE + 1));
+}
+
+#[test]
+#[should_panic]
+fn batch_mint_requires_owner_auth() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+
+    client.batch_mint(&recipients(&e, 2));
+}
+
+fn recipients(e: &Env, count: u32) -> Vec<Address> {
+    let mut to = Vec::new(e);
+    for _ in 0..count {
+        to.push_back(Address::generate(e));
+    }
+    to
+}
+
+#[test]
+fn total_minted_counts_burned_tokens() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client_with_max_supply(&e, &owner, Some(3));
+    e.mock_all_auths();
+
+    client.mint(&owner);
+    client.burn(&owner, &0);
+    client.batch_mint(&recipients(&e, 2));
+
+    assert_eq!(client.total_minted(), 3);
+    assert_eq!(client.total_supply(), 2);
+    assert_eq!(client.max_supply(), Some(3));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+fn batch_mint_beyond_max_supply_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client_with_max_supply(&e, &owner, Some(3));
+    e.mock_all_auths();
+
+    client.mint(&owner);
+    client.batch_mint(&recipients(&e, 3));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn mint_after_finish_minting_fails() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+
+    client.mint(&owner);
+    client.finish_minting();
+    assert!(client.is_minting_finished());
+
+    client.mint(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn finish_minting_requires_owner_auth() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+
+    client.finish_minting();
+}
+
+// ==================== Benchmarks ====================
+//
+// Run with `cargo test -p nft-enumerable-example bench -- --nocapture`.
+
+const BENCH_TOKENS: u32 = 50;
+
+#[test]
+fn bench_batch_mint_vs_mint() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner);
+    e.mock_all_auths();
+    let to = recipients(&e, BENCH_TOKENS);
+
+    // The budget covers the last top-level invocation, so sum it per call
+    let (mut mint_cpu, mut mint_mem) = (0u64, 0u64);
+    for recipient in to.iter() {
+        client.mint(&recipient);
+        mint_cpu += e.cost_estimate().budget().cpu_instruction_cost();
+        mint_mem += e.cost_estimate().budget().memory_bytes_cost();
+    }
+
+    client.batch_mint(&to);
+    let batch_cpu = e.cost_estimate().budget().cpu_instruction_cost();
+    let batch_mem = e.cost_estimate().budget().memory_bytes_cost();
+
+    std::println!("{BENCH_TOKENS} x mint: cpu {mint_cpu}, mem {mint_mem}");
+    std::println!("batch_mint of {BENCH_TOKENS}: cpu {batch_cpu}, mem {batch_mem}");
+    assert!(batch_cpu < mint_cpu);
+}
@@ -0,0 +1,11 @@
+//! Extensions shared by the NFT templates.
+//!
+//! Each module keeps its own storage keys and error codes (metadata 50-52,
+//! rental 60-61, supply 70-72), and leaves authorization to the calling
+//! contract.
+
+#![no_std]
+
+pub mod metadata;
+pub mod rental;
+pub mod supply;
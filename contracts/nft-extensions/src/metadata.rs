@@ -0,0 +1,130 @@
+//! Per-token URIs and metadata freezing.
+//!
+//! A token URI set with `set_token_uri` takes precedence over the one
+//! derived from the collection's base URI. Both can be changed until the
+//! metadata is frozen, either for a single token or, irreversibly, for the
+//! whole collection. Every change emits the ERC-4906 style events that
+//! marketplaces use to refresh cached metadata.
+//!
+//! Authorization is left to the calling contract. Functions that need the
+//! owner or default URI of a token are generic over the contract's
+//! `ContractType`, so tokens minted through `Consecutive` resolve lazily.
+
+use soroban_sdk::{contracterror, contractevent, contracttype, panic_with_error, Env, String};
+use stellar_tokens::non_fungible::{Base, ContractOverrides};
+
+/// Maximum length of a token URI, in bytes.
+pub const MAX_TOKEN_URI_LEN: u32 = 200;
+
+#[contracttype]
+pub enum MetadataStorageKey {
+    /// Whether the metadata of every token is frozen.
+    Frozen,
+    TokenFrozen(u32),
+    TokenUri(u32),
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MetadataError {
+    MetadataFrozen = 50,
+    TokenMetadataFrozen = 51,
+    InvalidTokenUri = 52,
+}
+
+/// Emitted when the metadata of a single token changes.
+#[contractevent]
+pub struct MetadataUpdateEvent {
+    pub token_id: u32,
+}
+
+/// Emitted when the metadata of a range of tokens changes.
+#[contractevent]
+pub struct BatchMetadataUpdateEvent {
+    pub from_token_id: u32,
+    pub to_token_id: u32,
+}
+
+#[contractevent]
+pub struct MetadataFrozenEvent {}
+
+#[contractevent]
+pub struct TokenMetadataFrozenEvent {
+    pub token_id: u32,
+}
+
+/// Returns the token's own URI if set, or the one derived from the base URI.
+pub fn token_uri<C: ContractOverrides>(e: &Env, token_id: u32) -> String {
+    get_token_uri(e, token_id).unwrap_or_else(|| C::token_uri(e, token_id))
+}
+
+/// Returns the URI set for the token with `set_token_uri`, if any.
+pub fn get_token_uri(e: &Env, token_id: u32) -> Option<String> {
+    e.storage().persistent().get(&MetadataStorageKey::TokenUri(token_id))
+}
+
+/// Sets the URI of an existing token.
+pub fn set_token_uri<C: ContractOverrides>(e: &Env, token_id: u32, uri: String) {
+    // Panics if the token does not exist
+    C::owner_of(e, token_id);
+    ensure_token_not_frozen(e, token_id);
+    if uri.len() == 0 || uri.len() > MAX_TOKEN_URI_LEN {
+        panic_with_error!(e, MetadataError::InvalidTokenUri);
+    }
+
+    e.storage().persistent().set(&MetadataStorageKey::TokenUri(token_id), &uri);
+
+    MetadataUpdateEvent { token_id }.publish(e);
+}
+
+/// Replaces the collection's base URI, keeping its name and symbol.
+pub fn set_base_uri(e: &Env, base_uri: String) {
+    ensure_not_frozen(e);
+
+    Base::set_metadata(e, base_uri, Base::name(e), Base::symbol(e));
+
+    BatchMetadataUpdateEvent { from_token_id: 0, to_token_id: u32::MAX }.publish(e);
+}
+
+/// Permanently freezes the metadata of every token.
+pub fn freeze_metadata(e: &Env) {
+    ensure_not_frozen(e);
+
+    e.storage().instance().set(&MetadataStorageKey::Frozen, &true);
+
+    MetadataFrozenEvent {}.publish(e);
+}
+
+/// Permanently freezes the URI of an existing token.
+pub fn freeze_token_metadata<C: ContractOverrides>(e: &Env, token_id: u32) {
+    // Panics if the token does not exist
+    C::owner_of(e, token_id);
+    ensure_token_not_frozen(e, token_id);
+
+    e.storage().persistent().set(&MetadataStorageKey::TokenFrozen(token_id), &true);
+
+    TokenMetadataFrozenEvent { token_id }.publish(e);
+}
+
+pub fn is_metadata_frozen(e: &Env) -> bool {
+    e.storage().instance().get(&MetadataStorageKey::Frozen).unwrap_or(false)
+}
+
+pub fn is_token_metadata_frozen(e: &Env, token_id: u32) -> bool {
+    is_metadata_frozen(e)
+        || e.storage().persistent().get(&MetadataStorageKey::TokenFrozen(token_id)).unwrap_or(false)
+}
+
+fn ensure_not_frozen(e: &Env) {
+    if is_metadata_frozen(e) {
+        panic_with_error!(e, MetadataError::MetadataFrozen);
+    }
+}
+
+fn ensure_token_not_frozen(e: &Env, token_id: u32) {
+    ensure_not_frozen(e);
+    if is_token_metadata_frozen(e, token_id) {
+        panic_with_error!(e, MetadataError::TokenMetadataFrozen);
+    }
+}
@@ -0,0 +1,94 @@
+//! Rentable tokens, following ERC-4907.
+//!
+//! The owner of a token, or an account approved for it, can grant a `user`
+//! role until a given ledger without transferring ownership. The user role
+//! lapses on its own once that ledger has passed, and is cleared whenever
+//! the token is transferred or burned.
+//!
+//! Token moves go through `stellar_tokens::non_fungible::Base`; the calling
+//! contract must call `clear_user` after each transfer and burn. `set_user`
+//! reads the owner through the contract's `ContractType`.
+
+use soroban_sdk::{contracterror, contractevent, contracttype, panic_with_error, Address, Env};
+use stellar_tokens::non_fungible::{Base, ContractOverrides};
+
+#[contracttype]
+pub enum RentalStorageKey {
+    User(u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserInfo {
+    pub user: Address,
+    /// Last ledger at which `user` holds the role.
+    pub expires_ledger: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RentalError {
+    NotOwnerOrApproved = 60,
+    InvalidExpiry = 61,
+}
+
+/// Emitted when the user of a token changes. `user` is `None` and
+/// `expires_ledger` is 0 when the role is cleared.
+#[contractevent]
+pub struct UpdateUserEvent {
+    #[topic]
+    pub token_id: u32,
+    pub user: Option<Address>,
+    pub expires_ledger: u32,
+}
+
+/// Grants `user` the user role of `token_id` until `expires_ledger`.
+/// `caller` must be the owner of the token or approved for it.
+pub fn set_user<C: ContractOverrides>(
+    e: &Env,
+    caller: &Address,
+    token_id: u32,
+    user: &Address,
+    expires_ledger: u32,
+) {
+    caller.require_auth();
+
+    let owner = C::owner_of(e, token_id);
+    let approved = Base::get_approved(e, token_id).as_ref() == Some(caller)
+        || Base::is_approved_for_all(e, &owner, caller);
+    if *caller != owner && !approved {
+        panic_with_error!(e, RentalError::NotOwnerOrApproved);
+    }
+    if expires_ledger < e.ledger().sequence() {
+        panic_with_error!(e, RentalError::InvalidExpiry);
+    }
+
+    let info = UserInfo { user: user.clone(), expires_ledger };
+    e.storage().persistent().set(&RentalStorageKey::User(token_id), &info);
+
+    UpdateUserEvent { token_id, user: Some(user.clone()), expires_ledger }.publish(e);
+}
+
+/// Returns the current user of `token_id`, or `None` if there is none or
+/// the role has expired.
+pub fn user_of(e: &Env, token_id: u32) -> Option<Address> {
+    let info: UserInfo = e.storage().persistent().get(&RentalStorageKey::User(token_id))?;
+    (e.ledger().sequence() <= info.expires_ledger).then_some(info.user)
+}
+
+/// Returns the last ledger of the current user role of `token_id`, or
+/// `None` if there is none or it has expired.
+pub fn user_expires(e: &Env, token_id: u32) -> Option<u32> {
+    let info: UserInfo = e.storage().persistent().get(&RentalStorageKey::User(token_id))?;
+    (e.ledger().sequence() <= info.expires_ledger).then_some(info.expires_ledger)
+}
+
+/// Removes the user role of `token_id`, if any.
+pub fn clear_user(e: &Env, token_id: u32) {
+    let key = RentalStorageKey::User(token_id);
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().remove(&key);
+        UpdateUserEvent { token_id, user: None, expires_ledger: 0 }.publish(e);
+    }
+}
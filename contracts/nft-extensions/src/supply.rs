@@ -0,0 +1,82 @@
+//! Supply cap and minting lifecycle.
+//!
+//! A collection can be deployed with a maximum number of tokens, and minting
+//! can be finished for good, after which no new token can ever be minted.
+//! `total_minted` counts every token minted so far, burned ones included, so
+//! burning never frees room under the cap.
+//!
+//! The calling contract must call `record_mint` before each mint and is in
+//! charge of authorizing `finish_minting`.
+
+use soroban_sdk::{contracterror, contractevent, contracttype, panic_with_error, Env};
+
+#[contracttype]
+pub enum SupplyStorageKey {
+    MaxSupply,
+    TotalMinted,
+    /// Whether minting has been finished for good.
+    MintingFinished,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SupplyError {
+    ExceededMaxSupply = 70,
+    MintingFinished = 71,
+    InvalidMaxSupply = 72,
+}
+
+#[contractevent]
+pub struct MintingFinishedEvent {
+    pub total_minted: u32,
+}
+
+/// Stores the supply cap, if any. Meant to be called from the constructor.
+pub fn set_max_supply(e: &Env, max_supply: Option<u32>) {
+    match max_supply {
+        Some(0) => panic_with_error!(e, SupplyError::InvalidMaxSupply),
+        Some(max_supply) => e.storage().instance().set(&SupplyStorageKey::MaxSupply, &max_supply),
+        None => {}
+    }
+}
+
+/// Accounts for `amount` new tokens, failing if minting is finished or the
+/// cap would be exceeded.
+pub fn record_mint(e: &Env, amount: u32) {
+    if is_minting_finished(e) {
+        panic_with_error!(e, SupplyError::MintingFinished);
+    }
+
+    let total_minted = total_minted(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, SupplyError::ExceededMaxSupply));
+    if max_supply(e).is_some_and(|max_supply| total_minted > max_supply) {
+        panic_with_error!(e, SupplyError::ExceededMaxSupply);
+    }
+
+    e.storage().instance().set(&SupplyStorageKey::TotalMinted, &total_minted);
+}
+
+/// Permanently disables minting.
+pub fn finish_minting(e: &Env) {
+    if is_minting_finished(e) {
+        panic_with_error!(e, SupplyError::MintingFinished);
+    }
+
+    e.storage().instance().set(&SupplyStorageKey::MintingFinished, &true);
+
+    MintingFinishedEvent { total_minted: total_minted(e) }.publish(e);
+}
+
+pub fn max_supply(e: &Env) -> Option<u32> {
+    e.storage().instance().get(&SupplyStorageKey::MaxSupply)
+}
+
+pub fn total_minted(e: &Env) -> u32 {
+    e.storage().instance().get(&SupplyStorageKey::TotalMinted).unwrap_or(0)
+}
+
+pub fn is_minting_finished(e: &Env) -> bool {
+    e.storage().instance().get(&SupplyStorageKey::MintingFinished).unwrap_or(false)
+}
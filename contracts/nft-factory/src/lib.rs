@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractevent, contractimpl, contracterror, contracttype, panic_with_error, Address, BytesN, Env,
-    IntoVal, String, Val, Vec,
+    contract, contractevent, contractimpl, contracterror, contracttype, panic_with_error, Address, Bytes, BytesN,
+    Env, IntoVal, String, Val, Vec,
 };
 
 /// NFTFactory - Deploys NFT contracts
@@ -11,6 +11,8 @@ use soroban_sdk::{
 /// - Enumerable NFT (track NFTs by owner)
 /// - Royalties NFT (creator royalties on resale)
 /// - Access Control NFT (role-based permissions)
+/// - Drop NFT (paid public sale with an optional allowlisted presale)
+/// - Soulbound NFT (non-transferable credentials)
 
 #[contract]
 pub struct NFTFactory;
@@ -23,6 +25,8 @@ pub enum DataKey {
     EnumerableWasm,
     RoyaltiesWasm,
     AccessControlWasm,
+    DropWasm,
+    SoulboundWasm,
     DeployedNFTs,
     NFTCount,
     Paused,                    // Emergency pause
@@ -34,6 +38,46 @@ pub enum NFTType {
     Enumerable,
     Royalties,
     AccessControl,
+    Drop,
+    Soulbound,
+}
+
+// Sale phase of a Drop NFT, mirrors the nft-drop template's `SalePhase`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SalePhase {
+    pub price: i128,
+    pub wallet_limit: u32,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+// Merkle-allowlisted presale of a Drop NFT, mirrors the template's `Presale`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Presale {
+    pub root: BytesN<32>,
+    pub phase: SalePhase,
+}
+
+// Hidden reveal of a Drop NFT, mirrors the template's `HiddenReveal`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HiddenReveal {
+    pub placeholder_uri: String,
+    pub commitment: BytesN<32>,
+}
+
+// Sale configuration of a Drop NFT, mirrors the template's `DropConfig`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DropConfig {
+    pub payment_token: Address,
+    pub payout: Address,
+    pub max_supply: u32,
+    pub public_sale: SalePhase,
+    pub presale: Option<Presale>,
+    pub hidden: Option<HiddenReveal>,
 }
 
 #[contracttype]
@@ -41,14 +85,36 @@ pub enum NFTType {
 pub struct NFTConfig {
     pub nft_type: NFTType,
     pub owner: Address,                     // For Enumerable NFT
-    pub admin: Option<Address>,             // For Royalties and Access Control NFTs
+    pub admin: Option<Address>,             // For every NFT type except Enumerable
     pub manager: Option<Address>,           // For Royalties NFT
     pub salt: BytesN<32>,
-    pub name: Option<String>,               // NFT collection name (default: "My Token")
-    pub symbol: Option<String>,             // NFT collection symbol (default: "TKN")
-    pub base_uri: Option<String>,           // Base URI for token metadata (default varies by type)
+    pub name: Option<String>,               // NFT collection name (default: DEFAULT_NAME)
+    pub symbol: Option<String>,             // NFT collection symbol (default: DEFAULT_SYMBOL)
+    pub base_uri: Option<String>,           // Base URI for token metadata (default: DEFAULT_BASE_URI)
+    pub use_defaults: bool,                 // Fill missing metadata with defaults instead of failing
+    pub royalty_receiver: Option<Address>,  // For Royalties NFT: default royalty receiver
+    pub royalty_basis_points: Option<u32>,  // For Royalties NFT: default royalty (max 10_000)
+    pub drop: Option<DropConfig>,           // For Drop NFT: sale configuration
+    pub holder_burnable: Option<bool>,      // For Soulbound NFT: holders may burn (default: false)
+    pub max_supply: Option<u32>,            // Supply cap (Enumerable, Royalties, AccessControl)
 }
 
+// Metadata defaults, only applied when `NFTConfig.use_defaults` is set
+pub const DEFAULT_NAME: &str = "My Token";
+pub const DEFAULT_SYMBOL: &str = "TKN";
+pub const DEFAULT_BASE_URI: &str = "https://example.com/nft/";
+
+// Metadata length bounds, in bytes
+pub const MAX_NAME_LEN: u32 = 30;
+pub const MAX_SYMBOL_LEN: u32 = 12;
+pub const MAX_BASE_URI_LEN: u32 = 200;
+
+// Royalty basis points upper bound (100%)
+pub const MAX_ROYALTY_BASIS_POINTS: u32 = 10_000;
+
+// Accepted base URI schemes
+const URI_SCHEMES: [&[u8]; 3] = [b"https://", b"ipfs://", b"ar://"];
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct NFTInfo {
@@ -59,6 +125,9 @@ pub struct NFTInfo {
     pub name: Option<String>,
     pub symbol: Option<String>,
     pub base_uri: Option<String>,
+    pub royalty_receiver: Option<Address>,
+    pub royalty_basis_points: Option<u32>,
+    pub max_supply: Option<u32>,
 }
 
 #[contractevent]
@@ -67,6 +136,8 @@ pub struct NFTDeployedEvent {
     pub nft_type: NFTType,
     pub deployer: Address,
     pub timestamp: u64,
+    pub royalty_receiver: Option<Address>,
+    pub royalty_basis_points: Option<u32>,
 }
 
 #[contractevent]
@@ -118,6 +189,18 @@ pub enum NFTFactoryError {
     NotPendingAdmin = 7,
     ContractPaused = 8,
     CounterOverflow = 9,
+    MissingMetadata = 10,
+    InvalidNameLength = 11,
+    InvalidNameCharacters = 12,
+    InvalidSymbolLength = 13,
+    InvalidSymbolCharacters = 14,
+    InvalidBaseUriLength = 15,
+    InvalidBaseUriCharacters = 16,
+    InvalidBaseUriScheme = 17,
+    MissingTrailingSlash = 18,
+    InvalidRoyalty = 19,
+    InvalidDropConfig = 20,
+    InvalidMaxSupply = 21,
 }
 
 #[contractimpl]
@@ -198,6 +281,46 @@ impl NFTFactory {
         .publish(&e);
     }
 
+    /// Set WASM hash for Drop NFT type
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (for authorization)
+    /// * `wasm_hash` - WASM hash of the Drop NFT contract
+    pub fn set_drop_wasm(e: Env, admin: Address, wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        Self::require_admin(&e, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::DropWasm, &wasm_hash);
+
+        // Emit event
+        WasmUpdatedEvent {
+            nft_type_name: soroban_sdk::String::from_str(&e, "Drop"),
+            wasm_hash: wasm_hash.clone(),
+        }
+        .publish(&e);
+    }
+
+    /// Set WASM hash for Soulbound NFT type
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (for authorization)
+    /// * `wasm_hash` - WASM hash of the Soulbound NFT contract
+    pub fn set_soulbound_wasm(e: Env, admin: Address, wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        Self::require_admin(&e, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::SoulboundWasm, &wasm_hash);
+
+        // Emit event
+        WasmUpdatedEvent {
+            nft_type_name: soroban_sdk::String::from_str(&e, "Soulbound"),
+            wasm_hash: wasm_hash.clone(),
+        }
+        .publish(&e);
+    }
+
     /// Deploy an NFT contract with specified configuration
     ///
     /// # Arguments
@@ -221,56 +344,107 @@ impl NFTFactory {
         // Validate config based on NFT type
         Self::validate_config(&e, &config);
 
-        // Get metadata with defaults
-        let name = config.name.clone().unwrap_or_else(|| String::from_str(&e, "My Token"));
-        let symbol = config.symbol.clone().unwrap_or_else(|| String::from_str(&e, "TKN"));
+        // Resolve metadata, applying defaults only when explicitly requested
+        let use_defaults = config.use_defaults;
+        let name = Self::resolve_metadata(&e, &config.name, DEFAULT_NAME, use_defaults);
+        let symbol = Self::resolve_metadata(&e, &config.symbol, DEFAULT_SYMBOL, use_defaults);
+        let base_uri = Self::resolve_metadata(&e, &config.base_uri, DEFAULT_BASE_URI, use_defaults);
+        Self::validate_metadata(&e, &name, &symbol, &base_uri);
 
         // Deploy using deployer pattern with constructor args based on NFT type
         let nft_address = match config.nft_type {
             NFTType::Enumerable => {
-                // Enumerable NFT constructor signature: (owner, base_uri, name, symbol)
-                let base_uri = config.base_uri.clone().unwrap_or_else(|| String::from_str(&e, "www.mytoken.com"));
+                // Enumerable NFT constructor signature:
+                // (owner, base_uri, name, symbol, max_supply)
                 let constructor_args: Vec<Val> = (
                     config.owner.clone(),
-                    base_uri,
+                    base_uri.clone(),
                     name.clone(),
                     symbol.clone(),
+                    config.max_supply,
                 ).into_val(&e);
                 e.deployer()
                     .with_address(e.current_contract_address(), config.salt)
                     .deploy_v2(wasm_hash, constructor_args)
             }
             NFTType::Royalties => {
-                // Royalties NFT constructor signature: (admin, manager, base_uri, name, symbol)
+                // Royalties NFT constructor signature:
+                // (admin, manager, base_uri, name, symbol, royalty_receiver, basis_points,
+                //  max_supply)
                 let admin = config.admin.clone().unwrap_or_else(|| {
                     panic_with_error!(&e, NFTFactoryError::InvalidConfig)
                 });
                 let manager = config.manager.clone().unwrap_or_else(|| {
                     panic_with_error!(&e, NFTFactoryError::InvalidConfig)
                 });
-                let base_uri = config.base_uri.clone().unwrap_or_else(|| String::from_str(&e, "https://example.com/nft/"));
+                let royalty_receiver = config.royalty_receiver.clone().unwrap_or_else(|| {
+                    panic_with_error!(&e, NFTFactoryError::InvalidConfig)
+                });
+                let basis_points = config.royalty_basis_points.unwrap_or_else(|| {
+                    panic_with_error!(&e, NFTFactoryError::InvalidConfig)
+                });
                 let constructor_args: Vec<Val> = (
                     admin,
                     manager,
-                    base_uri,
+                    base_uri.clone(),
                     name.clone(),
                     symbol.clone(),
+                    royalty_receiver,
+                    basis_points,
+                    config.max_supply,
                 ).into_val(&e);
                 e.deployer()
                     .with_address(e.current_contract_address(), config.salt)
                     .deploy_v2(wasm_hash, constructor_args)
             }
             NFTType::AccessControl => {
-                // Access Control NFT constructor signature: (admin, base_uri, name, symbol)
+                // Access Control NFT constructor signature:
+                // (admin, base_uri, name, symbol, max_supply)
                 let admin = config.admin.clone().unwrap_or_else(|| {
                     panic_with_error!(&e, NFTFactoryError::InvalidConfig)
                 });
-                let base_uri = config.base_uri.clone().unwrap_or_else(|| String::from_str(&e, "www.mytoken.com"));
                 let constructor_args: Vec<Val> = (
                     admin,
-                    base_uri,
+                    base_uri.clone(),
+                    name.clone(),
+                    symbol.clone(),
+                    config.max_supply,
+                ).into_val(&e);
+                e.deployer()
+                    .with_address(e.current_contract_address(), config.salt)
+                    .deploy_v2(wasm_hash, constructor_args)
+            }
+            NFTType::Drop => {
+                // Drop NFT constructor signature: (admin, base_uri, name, symbol, config)
+                let admin = config.admin.clone().unwrap_or_else(|| {
+                    panic_with_error!(&e, NFTFactoryError::InvalidConfig)
+                });
+                let drop = config.drop.clone().unwrap_or_else(|| {
+                    panic_with_error!(&e, NFTFactoryError::InvalidConfig)
+                });
+                let constructor_args: Vec<Val> = (
+                    admin,
+                    base_uri.clone(),
+                    name.clone(),
+                    symbol.clone(),
+                    drop,
+                ).into_val(&e);
+                e.deployer()
+                    .with_address(e.current_contract_address(), config.salt)
+                    .deploy_v2(wasm_hash, constructor_args)
+            }
+            NFTType::Soulbound => {
+                // Soulbound NFT constructor signature:
+                // (issuer, base_uri, name, symbol, holder_burnable)
+                let issuer = config.admin.clone().unwrap_or_else(|| {
+                    panic_with_error!(&e, NFTFactoryError::InvalidConfig)
+                });
+                let constructor_args: Vec<Val> = (
+                    issuer,
+                    base_uri.clone(),
                     name.clone(),
                     symbol.clone(),
+                    config.holder_burnable.unwrap_or(false),
                 ).into_val(&e);
                 e.deployer()
                     .with_address(e.current_contract_address(), config.salt)
@@ -278,6 +452,12 @@ impl NFTFactory {
             }
         };
 
+        // Drops record the supply cap of their sale configuration
+        let max_supply = match &config.drop {
+            Some(drop) => Some(drop.max_supply),
+            None => config.max_supply,
+        };
+
         // Store NFT info
         let nft_info = NFTInfo {
             address: nft_address.clone(),
@@ -286,7 +466,10 @@ impl NFTFactory {
             timestamp: e.ledger().timestamp(),
             name: Some(name),
             symbol: Some(symbol),
-            base_uri: config.base_uri.clone(),
+            base_uri: Some(base_uri),
+            royalty_receiver: config.royalty_receiver.clone(),
+            royalty_basis_points: config.royalty_basis_points,
+            max_supply,
         };
 
         let mut nfts: Vec<NFTInfo> = e
@@ -311,6 +494,8 @@ impl NFTFactory {
             nft_type: config.nft_type.clone(),
             deployer: deployer.clone(),
             timestamp: e.ledger().timestamp(),
+            royalty_receiver: config.royalty_receiver.clone(),
+            royalty_basis_points: config.royalty_basis_points,
         }
         .publish(&e);
 
@@ -525,6 +710,8 @@ impl NFTFactory {
             NFTType::Enumerable => DataKey::EnumerableWasm,
             NFTType::Royalties => DataKey::RoyaltiesWasm,
             NFTType::AccessControl => DataKey::AccessControlWasm,
+            NFTType::Drop => DataKey::DropWasm,
+            NFTType::Soulbound => DataKey::SoulboundWasm,
         };
 
         e.storage()
@@ -553,6 +740,137 @@ impl NFTFactory {
                 panic_with_error!(e, NFTFactoryError::InvalidConfig);
             }
         }
+
+        // Royalty parameters are required for Royalties NFT and rejected otherwise
+        if config.nft_type == NFTType::Royalties {
+            if config.royalty_receiver.is_none() || config.royalty_basis_points.is_none() {
+                panic_with_error!(e, NFTFactoryError::InvalidConfig);
+            }
+        } else if config.royalty_receiver.is_some() || config.royalty_basis_points.is_some() {
+            panic_with_error!(e, NFTFactoryError::InvalidConfig);
+        }
+
+        if let Some(basis_points) = config.royalty_basis_points {
+            if basis_points > MAX_ROYALTY_BASIS_POINTS {
+                panic_with_error!(e, NFTFactoryError::InvalidRoyalty);
+            }
+        }
+
+        // Drop NFT must have admin and sale configuration, and no manager
+        if config.nft_type == NFTType::Drop {
+            if config.admin.is_none() || config.manager.is_some() {
+                panic_with_error!(e, NFTFactoryError::InvalidConfig);
+            }
+            match &config.drop {
+                Some(drop) => Self::validate_drop(e, drop),
+                None => panic_with_error!(e, NFTFactoryError::InvalidConfig),
+            }
+        } else if config.drop.is_some() {
+            panic_with_error!(e, NFTFactoryError::InvalidConfig);
+        }
+
+        // Soulbound NFT must have admin as issuer, and no manager
+        if config.nft_type == NFTType::Soulbound {
+            if config.admin.is_none() || config.manager.is_some() {
+                panic_with_error!(e, NFTFactoryError::InvalidConfig);
+            }
+        } else if config.holder_burnable.is_some() {
+            panic_with_error!(e, NFTFactoryError::InvalidConfig);
+        }
+
+        // Supply cap is optional for Enumerable, Royalties and Access Control NFTs.
+        // Drop NFTs carry theirs in the sale configuration.
+        if let Some(max_supply) = config.max_supply {
+            if matches!(config.nft_type, NFTType::Drop | NFTType::Soulbound) {
+                panic_with_error!(e, NFTFactoryError::InvalidConfig);
+            }
+            if max_supply == 0 {
+                panic_with_error!(e, NFTFactoryError::InvalidMaxSupply);
+            }
+        }
+    }
+
+    // Helper: Validate Drop NFT sale configuration
+    fn validate_drop(e: &Env, drop: &DropConfig) {
+        if drop.max_supply == 0 {
+            panic_with_error!(e, NFTFactoryError::InvalidDropConfig);
+        }
+        Self::validate_sale_phase(e, &drop.public_sale);
+        if let Some(presale) = &drop.presale {
+            Self::validate_sale_phase(e, &presale.phase);
+        }
+        if let Some(hidden) = &drop.hidden {
+            // A single file, so no trailing slash is required
+            Self::validate_uri(e, &hidden.placeholder_uri);
+        }
+    }
+
+    // Helper: Validate price, wallet limit and ledger window of a sale phase
+    fn validate_sale_phase(e: &Env, phase: &SalePhase) {
+        if phase.price < 0 || phase.wallet_limit == 0 || phase.start_ledger > phase.end_ledger {
+            panic_with_error!(e, NFTFactoryError::InvalidDropConfig);
+        }
+    }
+
+    // Helper: Use the configured metadata value, or the default if allowed
+    fn resolve_metadata(
+        e: &Env,
+        value: &Option<String>,
+        default: &str,
+        use_defaults: bool,
+    ) -> String {
+        match value {
+            Some(value) => value.clone(),
+            None if use_defaults => String::from_str(e, default),
+            None => panic_with_error!(e, NFTFactoryError::MissingMetadata),
+        }
+    }
+
+    // Helper: Validate collection name, symbol and base URI
+    fn validate_metadata(e: &Env, name: &String, symbol: &String, base_uri: &String) {
+        // Name: 1-30 bytes, no control characters
+        if name.len() == 0 || name.len() > MAX_NAME_LEN {
+            panic_with_error!(e, NFTFactoryError::InvalidNameLength);
+        }
+        if name.to_bytes().iter().any(|byte| byte < 32 || byte == 127) {
+            panic_with_error!(e, NFTFactoryError::InvalidNameCharacters);
+        }
+
+        // Symbol: 1-12 ASCII letters and digits
+        if symbol.len() == 0 || symbol.len() > MAX_SYMBOL_LEN {
+            panic_with_error!(e, NFTFactoryError::InvalidSymbolLength);
+        }
+        if !symbol.to_bytes().iter().all(|byte| byte.is_ascii_alphanumeric()) {
+            panic_with_error!(e, NFTFactoryError::InvalidSymbolCharacters);
+        }
+
+        Self::validate_uri(e, base_uri);
+
+        // Token ids are appended directly to the base URI
+        if base_uri.to_bytes().last() != Some(b'/') {
+            panic_with_error!(e, NFTFactoryError::MissingTrailingSlash);
+        }
+    }
+
+    // Helper: Validate a base or placeholder URI
+    fn validate_uri(e: &Env, uri: &String) {
+        // Up to 200 bytes of printable ASCII without spaces
+        if uri.len() == 0 || uri.len() > MAX_BASE_URI_LEN {
+            panic_with_error!(e, NFTFactoryError::InvalidBaseUriLength);
+        }
+        let uri = uri.to_bytes();
+        if !uri.iter().all(|byte| byte.is_ascii_graphic()) {
+            panic_with_error!(e, NFTFactoryError::InvalidBaseUriCharacters);
+        }
+
+        // A supported scheme followed by a non-empty location
+        let has_scheme = URI_SCHEMES.iter().any(|scheme| {
+            let scheme_len = scheme.len() as u32;
+            uri.len() > scheme_len && uri.slice(0..scheme_len) == Bytes::from_slice(e, scheme)
+        });
+        if !has_scheme {
+            panic_with_error!(e, NFTFactoryError::InvalidBaseUriScheme);
+        }
     }
 
     // Helper: Check admin authorization
@@ -588,6 +906,8 @@ mod test {
         client.set_enumerable_wasm(&admin, &wasm_hash);
         client.set_royalties_wasm(&admin, &wasm_hash);
         client.set_access_control_wasm(&admin, &wasm_hash);
+        client.set_drop_wasm(&admin, &wasm_hash);
+        client.set_soulbound_wasm(&admin, &wasm_hash);
 
         (client, admin, wasm_hash)
     }
@@ -629,6 +949,8 @@ mod test {
         client.set_enumerable_wasm(&admin, &wasm_hash);
         client.set_royalties_wasm(&admin, &wasm_hash);
         client.set_access_control_wasm(&admin, &wasm_hash);
+        client.set_drop_wasm(&admin, &wasm_hash);
+        client.set_soulbound_wasm(&admin, &wasm_hash);
     }
 
     #[test]
@@ -670,6 +992,32 @@ mod test {
         client.set_access_control_wasm(&not_admin, &wasm_hash);
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1)")]
+    fn test_set_drop_wasm_not_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, _admin) = setup_nft_factory(&env);
+        let not_admin = Address::generate(&env);
+        let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+        client.set_drop_wasm(&not_admin, &wasm_hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1)")]
+    fn test_set_soulbound_wasm_not_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let (client, _admin) = setup_nft_factory(&env);
+        let not_admin = Address::generate(&env);
+        let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+        client.set_soulbound_wasm(&not_admin, &wasm_hash);
+    }
+
     // ===== Validation Tests =====
 
     #[test]
@@ -689,6 +1037,15 @@ mod test {
             admin: None, // Missing
             manager: Some(manager),
             salt,
+            name: None,
+            symbol: None,
+            base_uri: None,
+            use_defaults: true,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            drop: None,
+            holder_burnable: None,
+            max_supply: None,
         };
 
         client.deploy_nft(&deployer, &config);
@@ -711,11 +1068,90 @@ mod test {
             admin: Some(admin),
             manager: None, // Missing
             salt,
+            name: None,
+            symbol: None,
+            base_uri: None,
+            use_defaults: true,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            drop: None,
+            holder_burnable: None,
+            max_supply: None,
         };
 
         client.deploy_nft(&deployer, &config);
     }
 
+    fn royalties_config(
+        env: &Env,
+        receiver: Option<Address>,
+        basis_points: Option<u32>,
+    ) -> NFTConfig {
+        NFTConfig {
+            nft_type: NFTType::Royalties,
+            owner: Address::generate(env),
+            admin: Some(Address::generate(env)),
+            manager: Some(Address::generate(env)),
+            salt: BytesN::from_array(env, &[2u8; 32]),
+            name: None,
+            symbol: None,
+            base_uri: None,
+            use_defaults: true,
+            royalty_receiver: receiver,
+            royalty_basis_points: basis_points,
+            drop: None,
+            holder_burnable: None,
+            max_supply: None,
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_royalties_nft_missing_royalty_receiver() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let config = royalties_config(&env, None, Some(500));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_royalties_nft_missing_basis_points() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let config = royalties_config(&env, Some(Address::generate(&env)), None);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #19)")]
+    fn test_deploy_royalties_nft_basis_points_too_high() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let config = royalties_config(&env, Some(Address::generate(&env)), Some(10_001));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_enumerable_nft_with_royalty() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = royalties_config(&env, Some(Address::generate(&env)), Some(500));
+        config.nft_type = NFTType::Enumerable;
+        config.admin = None;
+        config.manager = None;
+        client.deploy_nft(&deployer, &config);
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #4)")]
     fn test_deploy_access_control_nft_missing_admin() {
@@ -732,6 +1168,15 @@ mod test {
             admin: None, // Missing
             manager: None,
             salt,
+            name: None,
+            symbol: None,
+            base_uri: None,
+            use_defaults: true,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            drop: None,
+            holder_burnable: None,
+            max_supply: None,
         };
 
         client.deploy_nft(&deployer, &config);
@@ -754,11 +1199,342 @@ mod test {
             admin: None,
             manager: None,
             salt,
+            name: None,
+            symbol: None,
+            base_uri: None,
+            use_defaults: true,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            drop: None,
+            holder_burnable: None,
+            max_supply: None,
         };
 
         client.deploy_nft(&deployer, &config);
     }
 
+    fn drop_config(env: &Env, drop: Option<DropConfig>) -> NFTConfig {
+        NFTConfig {
+            nft_type: NFTType::Drop,
+            owner: Address::generate(env),
+            admin: Some(Address::generate(env)),
+            manager: None,
+            salt: BytesN::from_array(env, &[4u8; 32]),
+            name: None,
+            symbol: None,
+            base_uri: None,
+            use_defaults: true,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            drop,
+            holder_burnable: None,
+            max_supply: None,
+        }
+    }
+
+    fn sale(env: &Env, max_supply: u32, phase: SalePhase) -> DropConfig {
+        DropConfig {
+            payment_token: Address::generate(env),
+            payout: Address::generate(env),
+            max_supply,
+            public_sale: phase,
+            presale: None,
+            hidden: None,
+        }
+    }
+
+    fn public_phase() -> SalePhase {
+        SalePhase { price: 100, wallet_limit: 3, start_ledger: 100, end_ledger: 200 }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_drop_nft_missing_sale_config() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let config = drop_config(&env, None);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_drop_nft_missing_admin() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, Some(sale(&env, 100, public_phase())));
+        config.admin = None;
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #20)")]
+    fn test_deploy_drop_nft_zero_max_supply() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let config = drop_config(&env, Some(sale(&env, 0, public_phase())));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #20)")]
+    fn test_deploy_drop_nft_inverted_sale_window() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut phase = public_phase();
+        phase.start_ledger = phase.end_ledger + 1;
+        let config = drop_config(&env, Some(sale(&env, 100, phase)));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #20)")]
+    fn test_deploy_drop_nft_invalid_presale() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut drop = sale(&env, 100, public_phase());
+        let mut phase = public_phase();
+        phase.wallet_limit = 0;
+        drop.presale = Some(Presale { root: BytesN::from_array(&env, &[5u8; 32]), phase });
+        let config = drop_config(&env, Some(drop));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #15)")]
+    fn test_deploy_drop_nft_empty_placeholder() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut drop = sale(&env, 100, public_phase());
+        drop.hidden = Some(HiddenReveal {
+            placeholder_uri: String::from_str(&env, ""),
+            commitment: BytesN::from_array(&env, &[6u8; 32]),
+        });
+        let config = drop_config(&env, Some(drop));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #17)")]
+    fn test_deploy_drop_nft_placeholder_invalid_scheme() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut drop = sale(&env, 100, public_phase());
+        drop.hidden = Some(HiddenReveal {
+            placeholder_uri: String::from_str(&env, "javascript:alert(1)"),
+            commitment: BytesN::from_array(&env, &[6u8; 32]),
+        });
+        let config = drop_config(&env, Some(drop));
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_enumerable_nft_with_sale_config() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, Some(sale(&env, 100, public_phase())));
+        config.nft_type = NFTType::Enumerable;
+        config.admin = None;
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_soulbound_nft_missing_issuer() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, None);
+        config.nft_type = NFTType::Soulbound;
+        config.admin = None;
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_access_control_nft_with_holder_burnable() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, None);
+        config.nft_type = NFTType::AccessControl;
+        config.holder_burnable = Some(true);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    // ===== Supply Cap Tests =====
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")]
+    fn test_deploy_nft_zero_max_supply() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, None);
+        config.nft_type = NFTType::AccessControl;
+        config.max_supply = Some(0);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_drop_nft_with_max_supply() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, Some(sale(&env, 100, public_phase())));
+        config.max_supply = Some(100);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_deploy_soulbound_nft_with_max_supply() {
+        let env = Env::default();
+        let (client, _admin, _wasm) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = drop_config(&env, None);
+        config.nft_type = NFTType::Soulbound;
+        config.max_supply = Some(100);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    // ===== Metadata Validation Tests =====
+
+    fn metadata_config(env: &Env, name: &str, symbol: &str, base_uri: &str) -> NFTConfig {
+        NFTConfig {
+            nft_type: NFTType::Enumerable,
+            owner: Address::generate(env),
+            admin: None,
+            manager: None,
+            salt: BytesN::from_array(env, &[3u8; 32]),
+            name: Some(String::from_str(env, name)),
+            symbol: Some(String::from_str(env, symbol)),
+            base_uri: Some(String::from_str(env, base_uri)),
+            use_defaults: false,
+            royalty_receiver: None,
+            royalty_basis_points: None,
+            drop: None,
+            holder_burnable: None,
+            max_supply: None,
+        }
+    }
+
+    fn deploy_with_metadata(name: &str, symbol: &str, base_uri: &str) {
+        let env = Env::default();
+        let (client, _admin, _wasm_hash) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let config = metadata_config(&env, name, symbol, base_uri);
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_deploy_nft_missing_metadata_without_defaults() {
+        let env = Env::default();
+        let (client, _admin, _wasm_hash) = setup_with_wasm(&env);
+        let deployer = Address::generate(&env);
+
+        let mut config = metadata_config(&env, "Collection", "COL", "https://example.com/nft/");
+        config.base_uri = None;
+        client.deploy_nft(&deployer, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #11)")]
+    fn test_deploy_nft_empty_name() {
+        deploy_with_metadata("", "COL", "https://example.com/nft/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #11)")]
+    fn test_deploy_nft_name_too_long() {
+        deploy_with_metadata(
+            "A Collection Name That Is Too Long",
+            "COL",
+            "https://example.com/nft/",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #12)")]
+    fn test_deploy_nft_name_control_character() {
+        deploy_with_metadata("Collection\n", "COL", "https://example.com/nft/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #13)")]
+    fn test_deploy_nft_symbol_too_long() {
+        deploy_with_metadata("Collection", "COLLECTIONSYM", "https://example.com/nft/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #14)")]
+    fn test_deploy_nft_symbol_invalid_characters() {
+        deploy_with_metadata("Collection", "CO-L", "https://example.com/nft/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #15)")]
+    fn test_deploy_nft_base_uri_too_long() {
+        let mut uri = [b'a'; 210];
+        uri[..20].copy_from_slice(b"https://example.com/");
+        uri[209] = b'/';
+        deploy_with_metadata("Collection", "COL", core::str::from_utf8(&uri).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #16)")]
+    fn test_deploy_nft_base_uri_with_space() {
+        deploy_with_metadata("Collection", "COL", "https://example.com/my nft/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #17)")]
+    fn test_deploy_nft_base_uri_without_scheme() {
+        deploy_with_metadata("Collection", "COL", "www.mytoken.com/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #17)")]
+    fn test_deploy_nft_base_uri_http_scheme() {
+        deploy_with_metadata("Collection", "COL", "http://example.com/nft/");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #17)")]
+    fn test_deploy_nft_base_uri_scheme_only() {
+        deploy_with_metadata("Collection", "COL", "ipfs://");
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #18)")]
+    fn test_deploy_nft_base_uri_missing_trailing_slash() {
+        deploy_with_metadata("Collection", "COL", "ipfs://bafybeigdyrzt5sfp7udm7hu76uh");
+    }
+
     // ===== Query Tests =====
 
     #[test]
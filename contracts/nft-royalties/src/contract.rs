@@ -3,22 +3,93 @@
 //! Demonstrates an example usage of the Royalties extension, allowing for
 //! setting and querying royalty information for NFTs following the ERC2981
 //! standard.
+//!
+//! A royalty can be split between several receivers. The manager stores a
+//! weighted split for the collection, which makes the contract itself the
+//! default royalty receiver. `royalty_splits` reports each receiver's share
+//! of a sale, and `distribute` pays out royalty proceeds held by the contract
+//! pro rata, with any rounding dust going to the first receiver.
+//!
+//! Accounts with the `metadata` role can set per-token URIs, change the base
+//! URI and freeze metadata.
+//!
+//! Tokens are minted through the Consecutive extension. `mint_range` mints a
+//! whole range of IDs to one account with a single ownership entry, similar
+//! to ERC-2309, and owners are resolved lazily on read. `batch_mint` mints
+//! one token to each of up to `MAX_BATCH_SIZE` recipients.
+//!
+//! The collection can be capped with `max_supply` at deployment, and the
+//! admin can irreversibly finish minting.
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+use nft_extensions::{metadata, supply};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error,
+    symbol_short, token, Address, Env, String, Vec, I256,
+};
 use stellar_access::access_control::{self as access_control, AccessControl};
 use stellar_macros::{default_impl, only_admin, only_role};
-use stellar_tokens::non_fungible::{royalties::NonFungibleRoyalties, Base, NonFungibleToken};
+use stellar_tokens::non_fungible::{
+    consecutive::{Consecutive, NonFungibleConsecutive},
+    royalties::NonFungibleRoyalties,
+    Base, NonFungibleToken,
+};
+
+/// Maximum number of receivers in a royalty split.
+pub const MAX_SPLIT_RECEIVERS: u32 = 10;
+
+/// Maximum number of recipients of a `batch_mint` call.
+pub const MAX_BATCH_SIZE: u32 = 100;
+
+#[contracttype]
+pub enum DataKey {
+    /// Weighted receivers of the collection royalty, in payout order.
+    RoyaltySplit,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExampleContractError {
+    InvalidSplit = 1,
+    SplitNotSet = 2,
+    InvalidAmount = 3,
+    InvalidBatchSize = 4,
+}
+
+#[contractevent]
+pub struct RoyaltySplitUpdatedEvent {
+    pub receivers: Vec<(Address, u32)>,
+    pub basis_points: u32,
+}
+
+#[contractevent]
+pub struct RoyaltyDistributedEvent {
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+}
 
 #[contract]
 pub struct ExampleContract;
 
 #[contractimpl]
 impl ExampleContract {
-    pub fn __constructor(e: &Env, admin: Address, manager: Address, base_uri: String, name: String, symbol: String) {
+    pub fn __constructor(
+        e: &Env,
+        admin: Address,
+        manager: Address,
+        base_uri: String,
+        name: String,
+        symbol: String,
+        royalty_receiver: Address,
+        basis_points: u32,
+        max_supply: Option<u32>,
+    ) {
         Base::set_metadata(e, base_uri, name, symbol);
+        supply::set_max_supply(e, max_supply);
 
-        // Set default royalty for the entire collection (10%)
-        Base::set_default_royalty(e, &admin, 1000);
+        // Set default royalty for the entire collection
+        Base::set_default_royalty(e, &royalty_receiver, basis_points);
 
         access_control::set_admin(e, &admin);
 
@@ -28,14 +99,52 @@ impl ExampleContract {
 
     #[only_admin]
     pub fn mint(e: &Env, to: Address) -> u32 {
-        // Mint token with sequential ID
-        Base::sequential_mint(e, &to)
+        supply::record_mint(e, 1);
+
+        // Mint a range of one token; returns its ID
+        Consecutive::batch_mint(e, &to, 1)
+    }
+
+    /// Mints `amount` consecutive tokens to `to`, storing their ownership in
+    /// a single entry.
+    ///
+    /// # Returns
+    /// ID of the first minted token
+    #[only_admin]
+    pub fn mint_range(e: &Env, to: Address, amount: u32) -> u32 {
+        if amount == 0 {
+            panic_with_error!(e, ExampleContractError::InvalidAmount);
+        }
+        supply::record_mint(e, amount);
+
+        // `batch_mint` returns the last ID of the range
+        Consecutive::batch_mint(e, &to, amount) + 1 - amount
+    }
+
+    /// Mints one token to each address in `to`, in order.
+    ///
+    /// # Returns
+    /// ID of the first minted token; the rest follow consecutively
+    #[only_admin]
+    pub fn batch_mint(e: &Env, to: Vec<Address>) -> u32 {
+        if to.is_empty() || to.len() > MAX_BATCH_SIZE {
+            panic_with_error!(e, ExampleContractError::InvalidBatchSize);
+        }
+        supply::record_mint(e, to.len());
+
+        let first_id = Consecutive::batch_mint(e, &to.get_unchecked(0), 1);
+        for recipient in to.iter().skip(1) {
+            Consecutive::batch_mint(e, &recipient, 1);
+        }
+        first_id
     }
 
     #[only_admin]
     pub fn mint_with_royalty(e: &Env, to: Address, receiver: Address, basis_points: u32) -> u32 {
-        // Mint token with sequential ID
-        let token_id = Base::sequential_mint(e, &to);
+        supply::record_mint(e, 1);
+
+        // Mint a range of one token
+        let token_id = Consecutive::batch_mint(e, &to, 1);
 
         // Set token-specific royalty
         Base::set_token_royalty(e, token_id, &receiver, basis_points);
@@ -43,17 +152,176 @@ impl ExampleContract {
         token_id
     }
 
+    /// Irreversibly disables minting.
+    #[only_admin]
+    pub fn finish_minting(e: &Env) {
+        supply::finish_minting(e);
+    }
+
+    /// Number of tokens minted so far.
+    pub fn total_minted(e: &Env) -> u32 {
+        supply::total_minted(e)
+    }
+
+    pub fn max_supply(e: &Env) -> Option<u32> {
+        supply::max_supply(e)
+    }
+
+    pub fn is_minting_finished(e: &Env) -> bool {
+        supply::is_minting_finished(e)
+    }
+
     pub fn get_royalty_info(e: &Env, token_id: u32, sale_price: i128) -> (Address, i128) {
         Base::royalty_info(e, token_id, sale_price)
     }
+
+    /// Stores a weighted split of the collection royalty and makes the
+    /// contract the default receiver, so proceeds can be distributed.
+    /// Tokens with their own royalty keep paying their own receiver.
+    #[only_role(operator, "manager")]
+    pub fn set_royalty_split(
+        e: &Env,
+        receivers: Vec<(Address, u32)>,
+        basis_points: u32,
+        operator: Address,
+    ) {
+        validate_split(e, &receivers);
+
+        e.storage().instance().set(&DataKey::RoyaltySplit, &receivers);
+        Base::set_default_royalty(e, &e.current_contract_address(), basis_points);
+
+        RoyaltySplitUpdatedEvent { receivers, basis_points }.publish(e);
+    }
+
+    /// Returns the collection royalty split, if one is set.
+    pub fn royalty_split(e: &Env) -> Option<Vec<(Address, u32)>> {
+        e.storage().instance().get(&DataKey::RoyaltySplit)
+    }
+
+    /// Returns each receiver's share of the royalty owed on `sale_price`.
+    pub fn royalty_splits(e: &Env, token_id: u32, sale_price: i128) -> Vec<(Address, i128)> {
+        let (receiver, amount) = Base::royalty_info(e, token_id, sale_price);
+
+        match Self::royalty_split(e) {
+            Some(receivers) if receiver == e.current_contract_address() => {
+                split_amount(e, &receivers, amount)
+            }
+            _ => Vec::from_array(e, [(receiver, amount)]),
+        }
+    }
+
+    /// Pays `amount` of the SEP-41 `token` held by the contract to the
+    /// split receivers, pro rata to their weights.
+    pub fn distribute(e: &Env, token: Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(e, ExampleContractError::InvalidAmount);
+        }
+        let receivers = Self::royalty_split(e)
+            .unwrap_or_else(|| panic_with_error!(e, ExampleContractError::SplitNotSet));
+
+        let client = token::Client::new(e, &token);
+        let contract_address = e.current_contract_address();
+        for (receiver, share) in split_amount(e, &receivers, amount).iter() {
+            if share > 0 {
+                client.transfer(&contract_address, &receiver, &share);
+            }
+        }
+
+        RoyaltyDistributedEvent { token, amount }.publish(e);
+    }
+
+    #[only_role(operator, "metadata")]
+    pub fn set_token_uri(e: &Env, token_id: u32, uri: String, operator: Address) {
+        metadata::set_token_uri::<Consecutive>(e, token_id, uri);
+    }
+
+    #[only_role(operator, "metadata")]
+    pub fn set_base_uri(e: &Env, base_uri: String, operator: Address) {
+        metadata::set_base_uri(e, base_uri);
+    }
+
+    /// Irreversibly freezes the metadata of every token.
+    #[only_role(operator, "metadata")]
+    pub fn freeze_metadata(e: &Env, operator: Address) {
+        metadata::freeze_metadata(e);
+    }
+
+    /// Irreversibly freezes the metadata of `token_id`.
+    #[only_role(operator, "metadata")]
+    pub fn freeze_token_metadata(e: &Env, token_id: u32, operator: Address) {
+        metadata::freeze_token_metadata::<Consecutive>(e, token_id);
+    }
+
+    pub fn is_metadata_frozen(e: &Env) -> bool {
+        metadata::is_metadata_frozen(e)
+    }
+
+    pub fn is_token_metadata_frozen(e: &Env, token_id: u32) -> bool {
+        metadata::is_token_metadata_frozen(e, token_id)
+    }
+}
+
+/// Checks that a split has 1 to `MAX_SPLIT_RECEIVERS` distinct receivers
+/// with non-zero weights, none of them the contract itself.
+fn validate_split(e: &Env, receivers: &Vec<(Address, u32)>) {
+    if receivers.is_empty() || receivers.len() > MAX_SPLIT_RECEIVERS {
+        panic_with_error!(e, ExampleContractError::InvalidSplit);
+    }
+
+    let contract_address = e.current_contract_address();
+    for (i, (receiver, weight)) in receivers.iter().enumerate() {
+        if weight == 0 || receiver == contract_address {
+            panic_with_error!(e, ExampleContractError::InvalidSplit);
+        }
+        for (other, _) in receivers.iter().skip(i + 1) {
+            if other == receiver {
+                panic_with_error!(e, ExampleContractError::InvalidSplit);
+            }
+        }
+    }
+}
+
+/// Splits `amount` by weight, rounding each share down. The first receiver
+/// also gets the rounding dust, so the shares always add up to `amount`.
+fn split_amount(e: &Env, receivers: &Vec<(Address, u32)>, amount: i128) -> Vec<(Address, i128)> {
+    let total_weight: i128 = receivers.iter().map(|(_, weight)| weight as i128).sum();
+    let total_weight = I256::from_i128(e, total_weight);
+
+    let mut shares = Vec::new(e);
+    let mut distributed = 0i128;
+    for (i, (receiver, weight)) in receivers.iter().enumerate() {
+        // The product can exceed i128 for large amounts, the share cannot
+        let share = if i == 0 {
+            0
+        } else {
+            I256::from_i128(e, amount)
+                .mul(&I256::from_i128(e, weight as i128))
+                .div(&total_weight)
+                .to_i128()
+                .expect("share is at most the amount")
+        };
+        distributed += share;
+        shares.push_back((receiver, share));
+    }
+
+    // The first receiver takes its own share plus the dust
+    let (first, _) = shares.get_unchecked(0);
+    shares.set(0, (first, amount - distributed));
+    shares
 }
 
 #[default_impl]
 #[contractimpl]
 impl NonFungibleToken for ExampleContract {
-    type ContractType = Base;
+    type ContractType = Consecutive;
+
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        metadata::token_uri::<Consecutive>(e, token_id)
+    }
 }
 
+impl NonFungibleConsecutive for ExampleContract {}
+
 #[contractimpl]
 impl NonFungibleRoyalties for ExampleContract {
     #[only_role(operator, "manager")]
@@ -1,11 +1,28 @@
 extern crate std;
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
 
-use crate::contract::{ExampleContract, ExampleContractClient};
+use crate::contract::{ExampleContract, ExampleContractClient, MAX_BATCH_SIZE};
 
 fn create_client<'a>(e: &Env, admin: &Address, manager: &Address) -> ExampleContractClient<'a> {
-    let address = e.register(ExampleContract, (admin, manager));
+    // Default royalty of 10% paid to the admin
+    create_client_with_royalty(e, admin, manager, admin, 1000)
+}
+
+fn create_client_with_royalty<'a>(
+    e: &Env,
+    admin: &Address,
+    manager: &Address,
+    royalty_receiver: &Address,
+    basis_points: u32,
+) -> ExampleContractClient<'a> {
+    let base_uri = String::from_str(e, "https://example.com/nft/");
+    let name = String::from_str(e, "Royalty NFT");
+    let symbol = String::from_str(e, "RNFT");
+    let address = e.register(
+        ExampleContract,
+        (admin, manager, base_uri, name, symbol, royalty_receiver, basis_points, None::<u32>),
+    );
     ExampleContractClient::new(e, &address)
 }
 
@@ -72,3 +89,527 @@ fn test_zero_royalty() {
     assert_eq!(receiver, royalty_receiver);
     assert_eq!(amount, 0); // 0% royalty
 }
+
+#[test]
+fn test_constructor_default_royalty() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let royalty_receiver = Address::generate(&e);
+    let client = create_client_with_royalty(&e, &admin, &manager, &royalty_receiver, 250);
+
+    e.mock_all_auths();
+
+    // Mint a token
+    let token_id = client.mint(&admin);
+
+    // Check royalty info (should use the constructor's 2.5% to the receiver)
+    let (receiver, amount) = client.get_royalty_info(&token_id, &10_000);
+    assert_eq!(receiver, royalty_receiver);
+    assert_eq!(amount, 250); // 2.5% of 10_000
+}
+
+#[test]
+#[should_panic]
+fn test_constructor_royalty_too_high() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    create_client_with_royalty(&e, &admin, &manager, &admin, 10_001);
+}
+
+// ==================== Royalty Split Tests ====================
+
+#[test]
+fn test_royalty_splits_follow_weights() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let developer = Address::generate(&e);
+    let dao = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let receivers = vec![&e, (artist.clone(), 50), (developer.clone(), 30), (dao.clone(), 20)];
+    client.set_royalty_split(&receivers, &1000, &manager);
+    assert_eq!(client.royalty_split(), Some(receivers));
+
+    let token_id = client.mint(&admin);
+
+    // The contract collects the collection royalty on behalf of the split
+    let (receiver, amount) = client.get_royalty_info(&token_id, &10_000);
+    assert_eq!(receiver, client.address);
+    assert_eq!(amount, 1000);
+
+    let splits = client.royalty_splits(&token_id, &10_000);
+    assert_eq!(splits, vec![&e, (artist, 500), (developer, 300), (dao, 200)]);
+}
+
+#[test]
+fn test_royalty_splits_rounding_dust_to_first_receiver() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let developer = Address::generate(&e);
+    let dao = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let receivers = vec![&e, (artist.clone(), 1), (developer.clone(), 1), (dao.clone(), 1)];
+    client.set_royalty_split(&receivers, &1000, &manager);
+    let token_id = client.mint(&admin);
+
+    // 10% of 1000 is 100, which does not split evenly in three
+    let splits = client.royalty_splits(&token_id, &1000);
+    assert_eq!(splits, vec![&e, (artist, 34), (developer, 33), (dao, 33)]);
+}
+
+#[test]
+fn test_royalty_splits_token_override() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let collector = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.set_royalty_split(&vec![&e, (artist.clone(), 1)], &1000, &manager);
+
+    // A token-specific royalty is paid to its own receiver, not the split
+    let token_id = client.mint_with_royalty(&admin, &collector, &500);
+    let splits = client.royalty_splits(&token_id, &2000);
+    assert_eq!(splits, vec![&e, (collector, 100)]);
+}
+
+#[test]
+fn test_royalty_splits_without_split() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let token_id = client.mint(&admin);
+    let splits = client.royalty_splits(&token_id, &1000);
+    assert_eq!(splits, vec![&e, (admin, 100)]);
+}
+
+#[test]
+fn test_distribute_pays_receivers_pro_rata() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let developer = Address::generate(&e);
+    let dao = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    let payment_token = token::Client::new(&e, &sac.address());
+    token::StellarAssetClient::new(&e, &sac.address()).mint(&client.address, &1000);
+
+    let receivers = vec![&e, (artist.clone(), 5), (developer.clone(), 3), (dao.clone(), 3)];
+    client.set_royalty_split(&receivers, &1000, &manager);
+
+    client.distribute(&sac.address(), &1000);
+
+    // 1000 * 3 / 11 = 272 each, the first receiver gets the remaining 456
+    assert_eq!(payment_token.balance(&artist), 456);
+    assert_eq!(payment_token.balance(&developer), 272);
+    assert_eq!(payment_token.balance(&dao), 272);
+    assert_eq!(payment_token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_distribute_max_amount_without_overflow() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let developer = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    let payment_token = token::Client::new(&e, &sac.address());
+    token::StellarAssetClient::new(&e, &sac.address()).mint(&client.address, &i128::MAX);
+
+    let receivers = vec![&e, (artist.clone(), 1), (developer.clone(), 1)];
+    client.set_royalty_split(&receivers, &1000, &manager);
+
+    client.distribute(&sac.address(), &i128::MAX);
+
+    assert_eq!(payment_token.balance(&developer), i128::MAX / 2);
+    assert_eq!(payment_token.balance(&artist), i128::MAX - i128::MAX / 2);
+    assert_eq!(payment_token.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_distribute_without_split() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    client.distribute(&sac.address(), &1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_distribute_zero_amount() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.set_royalty_split(&vec![&e, (artist, 1)], &1000, &manager);
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    client.distribute(&sac.address(), &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_set_royalty_split_empty() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.set_royalty_split(&vec![&e], &1000, &manager);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_set_royalty_split_zero_weight() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let dao = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.set_royalty_split(&vec![&e, (artist, 1), (dao, 0)], &1000, &manager);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_set_royalty_split_duplicate_receiver() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.set_royalty_split(&vec![&e, (artist.clone(), 1), (artist, 2)], &1000, &manager);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_set_royalty_split_not_manager() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let artist = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.set_royalty_split(&vec![&e, (artist.clone(), 1)], &1000, &artist);
+}
+
+// ==================== Metadata Tests ====================
+
+#[test]
+fn test_metadata_role_sets_token_uri() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let curator = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.grant_role(&admin, &curator, &soroban_sdk::Symbol::new(&e, "metadata"));
+    let token_id = client.mint(&admin);
+
+    let uri = String::from_str(&e, "https://example.com/art/1.json");
+    client.set_token_uri(&token_id, &uri, &curator);
+    assert_eq!(client.token_uri(&token_id), uri);
+
+    client.freeze_token_metadata(&token_id, &curator);
+    assert!(client.is_token_metadata_frozen(&token_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_set_token_uri_without_metadata_role() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let token_id = client.mint(&admin);
+    client.set_token_uri(&token_id, &String::from_str(&e, "ar://token"), &manager);
+}
+
+// ==================== Batch Minting Tests ====================
+
+fn recipients(e: &Env, count: u32) -> Vec<Address> {
+    let mut to = Vec::new(e);
+    for _ in 0..count {
+        to.push_back(Address::generate(e));
+    }
+    to
+}
+
+#[test]
+fn test_mint_range_resolves_owners() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let collector = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.mint(&admin);
+    let first_id = client.mint_range(&collector, &1000);
+
+    assert_eq!(first_id, 1);
+    assert_eq!(client.owner_of(&1), collector);
+    assert_eq!(client.owner_of(&500), collector);
+    assert_eq!(client.owner_of(&1000), collector);
+    assert_eq!(client.balance(&collector), 1000);
+    assert_eq!(client.mint(&admin), 1001);
+
+    let (receiver, amount) = client.get_royalty_info(&500, &1000);
+    assert_eq!(receiver, admin);
+    assert_eq!(amount, 100);
+}
+
+#[test]
+fn test_transfer_from_minted_range() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let collector = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.mint_range(&collector, &10);
+    client.transfer(&collector, &buyer, &4);
+
+    assert_eq!(client.owner_of(&3), collector);
+    assert_eq!(client.owner_of(&4), buyer);
+    assert_eq!(client.owner_of(&5), collector);
+    assert_eq!(client.balance(&collector), 9);
+    assert_eq!(client.balance(&buyer), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_mint_range_zero_amount() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.mint_range(&admin, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")]
+fn test_mint_range_not_admin() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.mint_range(&manager, &10);
+}
+
+#[test]
+fn test_batch_mint() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    let to = recipients(&e, 3);
+    let first_id = client.batch_mint(&to);
+
+    assert_eq!(first_id, 0);
+    for (i, recipient) in to.iter().enumerate() {
+        assert_eq!(client.owner_of(&(i as u32)), recipient);
+        assert_eq!(client.balance(&recipient), 1);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_batch_mint_too_large() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.batch_mint(&recipients(&e, MAX_BATCH_SIZE + 1));
+}
+
+// ==================== Supply Tests ====================
+
+fn create_client_with_max_supply<'a>(
+    e: &Env,
+    admin: &Address,
+    manager: &Address,
+    max_supply: u32,
+) -> ExampleContractClient<'a> {
+    let address = e.register(
+        ExampleContract,
+        (
+            admin,
+            manager,
+            String::from_str(e, "https://example.com/nft/"),
+            String::from_str(e, "Royalty NFT"),
+            String::from_str(e, "RNFT"),
+            admin,
+            1000u32,
+            Some(max_supply),
+        ),
+    );
+    ExampleContractClient::new(e, &address)
+}
+
+#[test]
+fn test_total_minted_across_mint_paths() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client_with_max_supply(&e, &admin, &manager, 10);
+
+    e.mock_all_auths();
+
+    client.mint(&admin);
+    client.mint_with_royalty(&admin, &manager, &500);
+    client.batch_mint(&recipients(&e, 3));
+    client.mint_range(&admin, &5);
+
+    assert_eq!(client.total_minted(), 10);
+    assert_eq!(client.max_supply(), Some(10));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+fn test_mint_range_beyond_max_supply() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client_with_max_supply(&e, &admin, &manager, 10);
+
+    e.mock_all_auths();
+
+    client.mint(&admin);
+    client.mint_range(&admin, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn test_mint_after_finish_minting() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    client.mint(&admin);
+    client.finish_minting();
+    assert!(client.is_minting_finished());
+
+    client.mint_range(&admin, &5);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_finish_minting_requires_admin_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    client.finish_minting();
+}
+
+// ==================== Benchmarks ====================
+//
+// Run with `cargo test -p nft-royalties-example bench -- --nocapture`.
+
+const BENCH_TOKENS: u32 = 50;
+
+#[test]
+fn bench_mint_paths() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let client = create_client(&e, &admin, &manager);
+
+    e.mock_all_auths();
+
+    // The budget covers the last top-level invocation, so sum it per call
+    let (mut mint_cpu, mut mint_mem) = (0u64, 0u64);
+    for _ in 0..BENCH_TOKENS {
+        client.mint(&admin);
+        mint_cpu += e.cost_estimate().budget().cpu_instruction_cost();
+        mint_mem += e.cost_estimate().budget().memory_bytes_cost();
+    }
+
+    client.batch_mint(&recipients(&e, BENCH_TOKENS));
+    let batch_cpu = e.cost_estimate().budget().cpu_instruction_cost();
+    let batch_mem = e.cost_estimate().budget().memory_bytes_cost();
+
+    client.mint_range(&admin, &BENCH_TOKENS);
+    let range_cpu = e.cost_estimate().budget().cpu_instruction_cost();
+    let range_mem = e.cost_estimate().budget().memory_bytes_cost();
+
+    std::println!("{BENCH_TOKENS} x mint: cpu {mint_cpu}, mem {mint_mem}");
+    std::println!("batch_mint of {BENCH_TOKENS}: cpu {batch_cpu}, mem {batch_mem}");
+    std::println!("mint_range of {BENCH_TOKENS}: cpu {range_cpu}, mem {range_mem}");
+    assert!(batch_cpu < mint_cpu);
+    assert!(range_cpu < batch_cpu);
+    assert!(range_mem < batch_mem);
+}
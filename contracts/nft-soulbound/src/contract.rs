@@ -0,0 +1,193 @@
+//! # Soulbound NFT Contract Example
+//!
+//! Non-transferable tokens for certificates, memberships and other
+//! credentials. Once issued, a token stays with its holder: `transfer`,
+//! `transfer_from`, `approve` and `approve_for_all` always fail.
+//!
+//! The issuer mints credentials, can revoke (burn) them at any time and can
+//! give each one an optional expiry timestamp. Holders may burn their own
+//! credentials only if the collection was deployed with `holder_burnable`.
+//! `valid(token_id)` reports whether a credential exists and has not expired.
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, Address,
+    Env, String,
+};
+use stellar_macros::default_impl;
+use stellar_tokens::non_fungible::{burnable::emit_burn, Base, NonFungibleToken};
+
+#[contracttype]
+pub enum DataKey {
+    Issuer,
+    HolderBurnable,
+    /// Present while the credential exists, holding its optional expiry.
+    Credential(u32),
+}
+
+#[contractevent]
+pub struct IssuedEvent {
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+    pub expires_at: Option<u64>,
+}
+
+#[contractevent]
+pub struct RevokedEvent {
+    #[topic]
+    pub holder: Address,
+    pub token_id: u32,
+}
+
+#[contractevent]
+pub struct ExpiryUpdatedEvent {
+    pub token_id: u32,
+    pub expires_at: Option<u64>,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoulboundError {
+    NonTransferable = 1,
+    HolderBurnDisabled = 2,
+    InvalidExpiry = 3,
+    CredentialNotFound = 4,
+}
+
+#[contract]
+pub struct SoulboundNFT;
+
+#[contractimpl]
+impl SoulboundNFT {
+    pub fn __constructor(
+        e: &Env,
+        issuer: Address,
+        base_uri: String,
+        name: String,
+        symbol: String,
+        holder_burnable: bool,
+    ) {
+        Base::set_metadata(e, base_uri, name, symbol);
+        e.storage().instance().set(&DataKey::Issuer, &issuer);
+        e.storage().instance().set(&DataKey::HolderBurnable, &holder_burnable);
+    }
+
+    /// Issues a credential to `to`, valid until the ledger timestamp
+    /// `expires_at` if given.
+    ///
+    /// # Returns
+    /// ID of the issued token
+    pub fn issue(e: &Env, to: Address, expires_at: Option<u64>) -> u32 {
+        get_issuer(e).require_auth();
+        check_expiry(e, expires_at);
+
+        let token_id = Base::sequential_mint(e, &to);
+        e.storage().persistent().set(&DataKey::Credential(token_id), &expires_at);
+
+        IssuedEvent { to, token_id, expires_at }.publish(e);
+        token_id
+    }
+
+    /// Burns a credential on behalf of the issuer.
+    pub fn revoke(e: &Env, token_id: u32) {
+        get_issuer(e).require_auth();
+
+        let holder = Base::owner_of(e, token_id);
+        Base::update(e, Some(&holder), None, token_id);
+        emit_burn(e, &holder, token_id);
+        e.storage().persistent().remove(&DataKey::Credential(token_id));
+
+        RevokedEvent { holder, token_id }.publish(e);
+    }
+
+    /// Lets the holder burn their own credential, if the collection allows it.
+    pub fn burn(e: &Env, from: Address, token_id: u32) {
+        if !Self::holder_burnable(e) {
+            panic_with_error!(e, SoulboundError::HolderBurnDisabled);
+        }
+
+        Base::burn(e, &from, token_id);
+        e.storage().persistent().remove(&DataKey::Credential(token_id));
+    }
+
+    /// Changes or clears the expiry of an existing credential.
+    pub fn set_expiry(e: &Env, token_id: u32, expires_at: Option<u64>) {
+        get_issuer(e).require_auth();
+        if !e.storage().persistent().has(&DataKey::Credential(token_id)) {
+            panic_with_error!(e, SoulboundError::CredentialNotFound);
+        }
+        check_expiry(e, expires_at);
+
+        e.storage().persistent().set(&DataKey::Credential(token_id), &expires_at);
+
+        ExpiryUpdatedEvent { token_id, expires_at }.publish(e);
+    }
+
+    /// Whether the credential exists and has not expired.
+    pub fn valid(e: &Env, token_id: u32) -> bool {
+        let credential: Option<Option<u64>> =
+            e.storage().persistent().get(&DataKey::Credential(token_id));
+        match credential {
+            None => false,
+            Some(None) => true,
+            Some(Some(expires_at)) => e.ledger().timestamp() < expires_at,
+        }
+    }
+
+    pub fn expires_at(e: &Env, token_id: u32) -> Option<u64> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Credential(token_id))
+            .unwrap_or_else(|| panic_with_error!(e, SoulboundError::CredentialNotFound))
+    }
+
+    pub fn issuer(e: &Env) -> Address {
+        get_issuer(e)
+    }
+
+    pub fn holder_burnable(e: &Env) -> bool {
+        e.storage().instance().get(&DataKey::HolderBurnable).unwrap_or(false)
+    }
+}
+
+// Transfers and approvals always fail, every other method is the default one
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for SoulboundNFT {
+    type ContractType = Base;
+
+    fn transfer(e: &Env, _from: Address, _to: Address, _token_id: u32) {
+        panic_with_error!(e, SoulboundError::NonTransferable)
+    }
+
+    fn transfer_from(e: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
+        panic_with_error!(e, SoulboundError::NonTransferable)
+    }
+
+    fn approve(
+        e: &Env,
+        _approver: Address,
+        _approved: Address,
+        _token_id: u32,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, SoulboundError::NonTransferable)
+    }
+
+    fn approve_for_all(e: &Env, _owner: Address, _operator: Address, _live_until_ledger: u32) {
+        panic_with_error!(e, SoulboundError::NonTransferable)
+    }
+}
+
+/// Expiries must lie in the future.
+fn check_expiry(e: &Env, expires_at: Option<u64>) {
+    if let Some(expires_at) = expires_at {
+        if expires_at <= e.ledger().timestamp() {
+            panic_with_error!(e, SoulboundError::InvalidExpiry);
+        }
+    }
+}
+
+fn get_issuer(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Issuer).expect("issuer should be set")
+}
@@ -0,0 +1,225 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, String,
+};
+
+use crate::contract::{SoulboundNFT, SoulboundNFTClient};
+
+const NOW: u64 = 1_000;
+
+fn create_client<'a>(e: &Env, issuer: &Address, holder_burnable: bool) -> SoulboundNFTClient<'a> {
+    e.ledger().with_mut(|li| li.timestamp = NOW);
+    let address = e.register(
+        SoulboundNFT,
+        (
+            issuer,
+            String::from_str(e, "https://example.com/badges/"),
+            String::from_str(e, "Membership"),
+            String::from_str(e, "MBR"),
+            holder_burnable,
+        ),
+    );
+    SoulboundNFTClient::new(e, &address)
+}
+
+fn set_timestamp(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn issue_mints_valid_credential() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &None);
+
+    assert_eq!(client.owner_of(&token_id), holder);
+    assert_eq!(client.balance(&holder), 1);
+    assert_eq!(client.expires_at(&token_id), None);
+    assert!(client.valid(&token_id));
+    assert!(!client.valid(&(token_id + 1)));
+}
+
+#[test]
+#[should_panic]
+fn issue_requires_issuer_auth() {
+    let e = Env::default();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    client.issue(&holder, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn transfer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &None);
+    client.transfer(&holder, &Address::generate(&e), &token_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn transfer_from_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &None);
+    client.transfer_from(&issuer, &holder, &issuer, &token_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn approve_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &None);
+    client.approve(&holder, &Address::generate(&e), &token_id, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn approve_for_all_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    client.issue(&holder, &None);
+    client.approve_for_all(&holder, &Address::generate(&e), &1_000);
+}
+
+#[test]
+fn credential_expires() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &Some(NOW + 100));
+    assert_eq!(client.expires_at(&token_id), Some(NOW + 100));
+    assert!(client.valid(&token_id));
+
+    set_timestamp(&e, NOW + 100);
+    assert!(!client.valid(&token_id));
+
+    // Expired credentials are still held, only no longer valid
+    assert_eq!(client.owner_of(&token_id), holder);
+}
+
+#[test]
+fn set_expiry_renews_credential() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &Some(NOW + 100));
+    set_timestamp(&e, NOW + 200);
+    assert!(!client.valid(&token_id));
+
+    client.set_expiry(&token_id, &Some(NOW + 300));
+    assert!(client.valid(&token_id));
+
+    client.set_expiry(&token_id, &None);
+    assert_eq!(client.expires_at(&token_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn issue_with_past_expiry_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    client.issue(&holder, &Some(NOW));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn set_expiry_unknown_credential_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    client.set_expiry(&0, &None);
+}
+
+#[test]
+fn issuer_can_revoke() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &None);
+    client.revoke(&token_id);
+
+    // The library burn event, then the revocation
+    assert_eq!(e.events().all().len(), 2);
+    assert_eq!(client.balance(&holder), 0);
+    assert!(!client.valid(&token_id));
+}
+
+#[test]
+#[should_panic]
+fn revoke_requires_issuer_auth() {
+    let e = Env::default();
+    let issuer = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    client.revoke(&0);
+}
+
+#[test]
+fn holder_can_burn_when_allowed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, true);
+
+    let token_id = client.issue(&holder, &None);
+    client.burn(&holder, &token_id);
+
+    assert_eq!(client.balance(&holder), 0);
+    assert!(!client.valid(&token_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn holder_cannot_burn_when_disabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let issuer = Address::generate(&e);
+    let holder = Address::generate(&e);
+    let client = create_client(&e, &issuer, false);
+
+    let token_id = client.issue(&holder, &None);
+    client.burn(&holder, &token_id);
+}
@@ -0,0 +1,64 @@
+//! Off-chain signed messages for token contracts.
+//!
+//! Accounts register an ed25519 public key once, then sign messages such as
+//! permits or delegations off-chain, and anyone can submit them. Each
+//! account has a single nonce shared by all of its messages, consumed on
+//! every successful verification, so a signature can only be used once.
+//!
+//! What is signed, expiry and authorization of the registration are left to
+//! the calling contract.
+
+#![no_std]
+
+use soroban_sdk::{contracterror, contracttype, panic_with_error, Address, Bytes, BytesN, Env};
+
+#[contracttype]
+pub enum SignatureStorageKey {
+    PublicKey(Address),
+    Nonce(Address),
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SignatureError {
+    PublicKeyNotRegistered = 80,
+    InvalidNonce = 81,
+}
+
+/// Stores the ed25519 public key `account` signs messages with, replacing
+/// any previous one.
+pub fn set_public_key(e: &Env, account: &Address, public_key: &BytesN<32>) {
+    e.storage().persistent().set(&SignatureStorageKey::PublicKey(account.clone()), public_key);
+}
+
+pub fn public_key(e: &Env, account: &Address) -> Option<BytesN<32>> {
+    e.storage().persistent().get(&SignatureStorageKey::PublicKey(account.clone()))
+}
+
+/// Returns the nonce the next message from `account` must use.
+pub fn nonce(e: &Env, account: &Address) -> u64 {
+    e.storage().persistent().get(&SignatureStorageKey::Nonce(account.clone())).unwrap_or(0)
+}
+
+/// Checks `signature` over `message` against the key registered by `account`
+/// and consumes `nonce`.
+pub fn verify_signature(
+    e: &Env,
+    account: &Address,
+    nonce: u64,
+    message: Bytes,
+    signature: &BytesN<64>,
+) {
+    let public_key = public_key(e, account)
+        .unwrap_or_else(|| panic_with_error!(e, SignatureError::PublicKeyNotRegistered));
+
+    let expected = self::nonce(e, account);
+    if nonce != expected {
+        panic_with_error!(e, SignatureError::InvalidNonce);
+    }
+
+    // Traps if the signature does not match
+    e.crypto().ed25519_verify(&public_key, &message, signature);
+    e.storage().persistent().set(&SignatureStorageKey::Nonce(account.clone()), &(expected + 1));
+}
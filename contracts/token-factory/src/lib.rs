@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractevent, contractimpl, contracterror, contracttype, panic_with_error, Address, BytesN, Env,
-    IntoVal, String, Val, Vec,
+    contract, contractclient, contractevent, contractimpl, contracterror, contracttype, panic_with_error, token,
+    Address, Bytes, BytesN, Env, IntoVal, String, Val, Vec,
 };
 
 /// TokenFactory - Deploys fungible token contracts
@@ -13,6 +13,14 @@ use soroban_sdk::{
 /// - Capped Token (max supply limit)
 /// - Pausable Token (emergency stop)
 /// - Vault Token (time-locked tokens)
+/// - Composable Token (any valid combination of the features above)
+///
+/// It also deploys Vesting Wallets that lock an existing token for a set of
+/// beneficiaries, funded from the deployer in the same call, and Merkle
+/// Airdrops that distribute the whole supply of a newly deployed token.
+///
+/// Classic Stellar assets can be listed in the same registry: the factory
+/// deploys their Stellar Asset Contract, or looks it up if it already exists.
 
 #[contract]
 pub struct TokenFactory;
@@ -27,6 +35,9 @@ pub enum DataKey {
     CappedWasm,
     PausableWasm,
     VaultWasm,
+    ComposableWasm,
+    VestingWasm,
+    AirdropWasm,
     DeployedTokens,
     TokenCount,
     Paused,                      // Emergency pause
@@ -40,16 +51,37 @@ pub enum TokenType {
     Capped,
     Pausable,
     Vault,
+    Composable,
+    Vesting,
+    Airdrop,
+    StellarAsset,
 }
 
+// Feature flags for Composable tokens, matching the `fungible-composable` template
+pub const FEATURE_CAPPED: u32 = 1 << 0;
+pub const FEATURE_PAUSABLE: u32 = 1 << 1;
+pub const FEATURE_ALLOWLIST: u32 = 1 << 2;
+pub const FEATURE_BLOCKLIST: u32 = 1 << 3;
+pub const FEATURE_BURNABLE: u32 = 1 << 4;
+pub const FEATURE_MINTABLE: u32 = 1 << 5;
+pub const ALL_FEATURES: u32 = FEATURE_CAPPED
+    | FEATURE_PAUSABLE
+    | FEATURE_ALLOWLIST
+    | FEATURE_BLOCKLIST
+    | FEATURE_BURNABLE
+    | FEATURE_MINTABLE;
+
+// Ceiling on the management and performance fee rates of deployed vaults (20%)
+pub const VAULT_MAX_FEE_BPS: u32 = 2_000;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TokenConfig {
     pub token_type: TokenType,
     pub admin: Address,
     pub manager: Address,
-    pub initial_supply: i128,
-    pub cap: Option<i128>, // Only for Capped tokens
+    pub initial_supply: i128, // For Vault: optional seed deposit from the deployer, as dead shares
+    pub cap: Option<i128>, // For Capped tokens and Composable tokens with FEATURE_CAPPED
     pub name: String,
     pub symbol: String,
     pub decimals: u32,
@@ -57,6 +89,54 @@ pub struct TokenConfig {
     // Vault-specific parameters
     pub asset: Option<Address>,          // For Vault: underlying asset address
     pub decimals_offset: Option<u32>,    // For Vault: decimals offset
+    // Composable-specific parameters
+    pub features: Option<u32>,           // For Composable: bitmask of FEATURE_* flags
+    // Initial distribution, minted on top of `initial_supply` (which goes to the admin)
+    pub allocations: Option<Vec<(Address, i128)>>, // (recipient, amount) pairs
+}
+
+/// Vesting schedule for one beneficiary, matching the `token-vesting` template
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingParams {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub start: u64,            // Ledger timestamp at which vesting begins
+    pub cliff_seconds: u64,    // Nothing is releasable before start + cliff_seconds
+    pub duration_seconds: u64, // Everything is releasable from start + duration_seconds
+    pub revocable: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingConfig {
+    pub admin: Address,                 // Can revoke revocable schedules
+    pub token: Address,                 // SEP-41 token being vested
+    pub schedules: Vec<VestingParams>,  // Funded from the deployer on deployment
+    pub salt: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropConfig {
+    pub admin: Address,          // Can sweep unclaimed tokens after the deadline
+    pub token: TokenConfig,      // New token; its initial supply is minted to the airdrop
+    pub root_hash: BytesN<32>,   // Merkle root of (index, account, amount) leaves
+    pub deadline: u64,           // Ledger timestamp after which claims are closed
+    pub salt: BytesN<32>,
+}
+
+/// Subset of the `fungible-vault` template interface used to seed new vaults
+#[contractclient(name = "VaultClient")]
+pub trait VaultInterface {
+    fn deposit(e: Env, assets: i128, receiver: Address, from: Address, operator: Address) -> i128;
+}
+
+/// Feature parameters passed to the Composable token constructor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureConfig {
+    pub cap: Option<i128>,
 }
 
 #[contracttype]
@@ -67,6 +147,16 @@ pub struct TokenInfo {
     pub admin: Address,
     pub timestamp: u64,
     pub name: Option<String>,
+    pub asset: Option<AssetInfo>, // For Vault: underlying asset; for StellarAsset: the asset itself
+}
+
+/// Metadata of a SEP-41 asset, read from the asset at deployment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetInfo {
+    pub address: Address,
+    pub symbol: String,
+    pub decimals: u32,
 }
 
 #[contractevent]
@@ -77,6 +167,33 @@ pub struct TokenDeployedEvent {
     pub name: String,
     pub symbol: String,
     pub timestamp: u64,
+    pub allocations: Vec<(Address, i128)>,
+}
+
+#[contractevent]
+pub struct VestingDeployedEvent {
+    pub vesting_address: Address,
+    pub token: Address,
+    pub deployer: Address,
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+#[contractevent]
+pub struct AirdropDeployedEvent {
+    pub airdrop_address: Address,
+    pub token: Address,
+    pub deployer: Address,
+    pub total_amount: i128,
+    pub deadline: u64,
+}
+
+#[contractevent]
+pub struct StellarAssetRegisteredEvent {
+    pub asset_address: Address,
+    pub deployer: Address,
+    pub name: String,
+    pub deployed: bool, // false when the Stellar Asset Contract already existed
 }
 
 #[contractevent]
@@ -137,8 +254,21 @@ pub enum TokenFactoryError {
     NoPendingAdmin = 16,
     NotPendingAdmin = 17,
     ContractPaused = 18,
+    InvalidFeatures = 19,
+    ConflictingFeatures = 20,
+    InvalidSchedule = 21,
+    InvalidAirdrop = 22,
+    InvalidAsset = 23,
+    InvalidAllocation = 24,
+    AlreadyRegistered = 25,
 }
 
+// Upper bound on any supply or amount, to prevent overflow issues
+const MAX_SUPPLY: i128 = i128::MAX / 2;
+
+// Upper bound on initial allocations, as each one is a balance write in the constructor
+const MAX_ALLOCATIONS: u32 = 40;
+
 #[contractimpl]
 impl TokenFactory {
     /// Initialize TokenFactory with admin address
@@ -245,8 +375,66 @@ impl TokenFactory {
         .publish(&e);
     }
 
+    /// Set WASM hash for Composable token type
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (for authorization)
+    /// * `wasm_hash` - WASM hash of the Composable token contract
+    pub fn set_composable_wasm(e: Env, admin: Address, wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        Self::require_admin(&e, &admin);
+        e.storage().instance().set(&DataKey::ComposableWasm, &wasm_hash);
+
+        // Emit event
+        WasmUpdatedEvent {
+            token_type_name: String::from_str(&e, "Composable"),
+            wasm_hash: wasm_hash.clone(),
+        }
+        .publish(&e);
+    }
+
+    /// Set WASM hash for Vesting Wallet type
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (for authorization)
+    /// * `wasm_hash` - WASM hash of the Vesting Wallet contract
+    pub fn set_vesting_wasm(e: Env, admin: Address, wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        Self::require_admin(&e, &admin);
+        e.storage().instance().set(&DataKey::VestingWasm, &wasm_hash);
+
+        // Emit event
+        WasmUpdatedEvent {
+            token_type_name: String::from_str(&e, "Vesting"),
+            wasm_hash: wasm_hash.clone(),
+        }
+        .publish(&e);
+    }
+
+    /// Set WASM hash for Merkle Airdrop type
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (for authorization)
+    /// * `wasm_hash` - WASM hash of the Merkle Airdrop contract
+    pub fn set_airdrop_wasm(e: Env, admin: Address, wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        Self::require_admin(&e, &admin);
+        e.storage().instance().set(&DataKey::AirdropWasm, &wasm_hash);
+
+        // Emit event
+        WasmUpdatedEvent {
+            token_type_name: String::from_str(&e, "Airdrop"),
+            wasm_hash: wasm_hash.clone(),
+        }
+        .publish(&e);
+    }
+
     /// Deploy a token contract with specified configuration
     ///
+    /// For Vault tokens, a positive `initial_supply` is deposited from the
+    /// deployer into the new vault, so the deployer must also authorize that
+    /// deposit.
+    ///
     /// # Arguments
     /// * `deployer` - Address calling this function
     /// * `config` - Token configuration including type, admin, supply, etc.
@@ -262,114 +450,211 @@ impl TokenFactory {
             panic_with_error!(&e, TokenFactoryError::ContractPaused);
         }
 
-        // Get WASM hash based on token type
-        let wasm_hash = Self::get_wasm_for_type(&e, &config.token_type);
+        Self::deploy_from_config(&e, &deployer, config)
+    }
 
-        // Validate config based on token type
-        Self::validate_config(&e, &config);
+    /// Deploy a new token together with a Merkle airdrop distributing its
+    /// initial supply
+    ///
+    /// The initial supply is minted straight into the distributor through the
+    /// token's initial allocations, next to any allocations in the token config.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address calling this function
+    /// * `config` - Airdrop configuration including the new token's configuration
+    ///
+    /// # Returns
+    /// Address of the deployed airdrop contract
+    pub fn deploy_airdrop(e: Env, deployer: Address, config: AirdropConfig) -> Address {
+        deployer.require_auth();
 
-        // Deploy contract - deploy_v2 requires constructor_args as Vec<Val>, not tuple
-        let token_address = match config.token_type {
-            TokenType::Capped => {
-                // Capped token requires cap parameter - safe unwrap after validation
-                let cap = config.cap.unwrap_or_else(|| {
-                    panic_with_error!(&e, TokenFactoryError::MissingCap)
-                });
+        // Check if contract is paused
+        let paused = e.storage().instance().get(&DataKey::Paused).unwrap_or(false);
+        if paused {
+            panic_with_error!(&e, TokenFactoryError::ContractPaused);
+        }
 
-                // Convert constructor args to Vec<Val>
-                let constructor_args: Vec<Val> = (
-                    config.admin.clone(),
-                    config.manager.clone(),
-                    config.initial_supply,
-                    cap,
-                    config.name.clone(),
-                    config.symbol.clone(),
-                    config.decimals,
-                ).into_val(&e);
+        let wasm_hash = Self::get_wasm_for_type(&e, &TokenType::Airdrop);
+        Self::validate_airdrop_config(&e, &config);
 
-                e.deployer()
-                    .with_address(e.current_contract_address(), config.salt)
-                    .deploy_v2(wasm_hash, constructor_args)
-            }
-            TokenType::Vault => {
-                // Vault tokens have a different constructor signature: (asset, decimals_offset)
-                // Validation ensures these fields are present
-                let asset = config.asset.clone().unwrap_or_else(|| {
-                    panic_with_error!(&e, TokenFactoryError::InvalidConfig)
-                });
-                let decimals_offset = config.decimals_offset.unwrap_or_else(|| {
-                    panic_with_error!(&e, TokenFactoryError::InvalidConfig)
-                });
+        // The distributor address is known before deployment, so the token can
+        // mint the airdropped supply to it from its constructor
+        let airdrop_deployer = e.deployer().with_address(e.current_contract_address(), config.salt);
+        let airdrop_address = airdrop_deployer.deployed_address();
 
-                // Convert constructor args to Vec<Val>
-                let constructor_args: Vec<Val> = (asset, decimals_offset).into_val(&e);
+        let total_amount = config.token.initial_supply;
+        let mut allocations = Vec::from_array(&e, [(airdrop_address.clone(), total_amount)]);
+        if let Some(extra) = &config.token.allocations {
+            allocations.append(extra);
+        }
+        let token_config = TokenConfig {
+            initial_supply: 0,
+            allocations: Some(allocations),
+            ..config.token.clone()
+        };
+        let token_address = Self::deploy_from_config(&e, &deployer, token_config);
+
+        // Airdrop constructor signature: (admin, token, root_hash, deadline)
+        let constructor_args: Vec<Val> = (
+            config.admin.clone(),
+            token_address.clone(),
+            config.root_hash.clone(),
+            config.deadline,
+        ).into_val(&e);
+
+        airdrop_deployer.deploy_v2(wasm_hash, constructor_args);
+
+        Self::register_token(
+            &e,
+            TokenInfo {
+                address: airdrop_address.clone(),
+                token_type: TokenType::Airdrop,
+                admin: config.admin.clone(),
+                timestamp: e.ledger().timestamp(),
+                name: None,
+                asset: None,
+            },
+        );
 
-                e.deployer()
-                    .with_address(e.current_contract_address(), config.salt)
-                    .deploy_v2(wasm_hash, constructor_args)
-            }
-            _ => {
-                // Other token types use standard constructor
-                // Convert constructor args to Vec<Val>
-                let constructor_args: Vec<Val> = (
-                    config.admin.clone(),
-                    config.manager.clone(),
-                    config.initial_supply,
-                    config.name.clone(),
-                    config.symbol.clone(),
-                    config.decimals,
-                ).into_val(&e);
+        // Emit event
+        AirdropDeployedEvent {
+            airdrop_address: airdrop_address.clone(),
+            token: token_address,
+            deployer: deployer.clone(),
+            total_amount,
+            deadline: config.deadline,
+        }
+        .publish(&e);
 
-                e.deployer()
-                    .with_address(e.current_contract_address(), config.salt)
-                    .deploy_v2(wasm_hash, constructor_args)
-            }
-        };
+        airdrop_address
+    }
 
-        // Update state AFTER successful deployment
-        // Increment token count with overflow protection
-        let count: u32 = e.storage().instance().get(&DataKey::TokenCount).unwrap_or(0);
-        let new_count = count.checked_add(1)
-            .unwrap_or_else(|| {
-                panic_with_error!(&e, TokenFactoryError::CounterOverflow)
-            });
+    /// Deploy a vesting wallet and fund it from the deployer
+    ///
+    /// # Arguments
+    /// * `deployer` - Address calling this function, funds all schedules
+    /// * `config` - Vesting configuration including token, admin and schedules
+    ///
+    /// # Returns
+    /// Address of the deployed vesting wallet
+    pub fn deploy_vesting(e: Env, deployer: Address, config: VestingConfig) -> Address {
+        deployer.require_auth();
 
-        // Store token info
-        let token_info = TokenInfo {
-            address: token_address.clone(),
-            token_type: config.token_type.clone(),
-            admin: config.admin.clone(),
+        // Check if contract is paused
+        let paused = e.storage().instance().get(&DataKey::Paused).unwrap_or(false);
+        if paused {
+            panic_with_error!(&e, TokenFactoryError::ContractPaused);
+        }
+
+        let wasm_hash = Self::get_wasm_for_type(&e, &TokenType::Vesting);
+
+        // Validate schedules and compute the amount to lock
+        let total_amount = Self::validate_vesting_config(&e, &config);
+
+        // Vesting constructor signature: (admin, token, schedules)
+        let constructor_args: Vec<Val> = (
+            config.admin.clone(),
+            config.token.clone(),
+            config.schedules.clone(),
+        ).into_val(&e);
+
+        let vesting_address = e.deployer()
+            .with_address(e.current_contract_address(), config.salt)
+            .deploy_v2(wasm_hash, constructor_args);
+
+        // Fund all schedules from the deployer
+        token::TokenClient::new(&e, &config.token).transfer(&deployer, &vesting_address, &total_amount);
+
+        Self::register_token(
+            &e,
+            TokenInfo {
+                address: vesting_address.clone(),
+                token_type: TokenType::Vesting,
+                admin: config.admin.clone(),
+                timestamp: e.ledger().timestamp(),
+                name: None,
+                asset: None,
+            },
+        );
+
+        // Emit event
+        VestingDeployedEvent {
+            vesting_address: vesting_address.clone(),
+            token: config.token.clone(),
+            deployer: deployer.clone(),
+            total_amount,
             timestamp: e.ledger().timestamp(),
-            name: Some(config.name.clone()),
-        };
+        }
+        .publish(&e);
 
-        let mut tokens: Vec<TokenInfo> = e
-            .storage()
-            .instance()
-            .get(&DataKey::DeployedTokens)
-            .unwrap_or_else(|| Vec::new(&e));
-        tokens.push_back(token_info);
-        e.storage()
-            .instance()
-            .set(&DataKey::DeployedTokens, &tokens);
+        vesting_address
+    }
 
-        // Update token count
-        e.storage()
-            .instance()
-            .set(&DataKey::TokenCount, &new_count);
+    /// Deploy the Stellar Asset Contract of a classic asset, or look it up
+    /// if it already exists, and register it
+    ///
+    /// # Arguments
+    /// * `deployer` - Address calling this function
+    /// * `serialized_asset` - XDR-serialized `Asset` (code and issuer)
+    ///
+    /// # Returns
+    /// Address of the Stellar Asset Contract
+    pub fn deploy_stellar_asset(e: Env, deployer: Address, serialized_asset: Bytes) -> Address {
+        deployer.require_auth();
+
+        // Check if contract is paused
+        let paused = e.storage().instance().get(&DataKey::Paused).unwrap_or(false);
+        if paused {
+            panic_with_error!(&e, TokenFactoryError::ContractPaused);
+        }
+
+        let asset_deployer = e.deployer().with_stellar_asset(serialized_asset);
+        let asset_address = asset_deployer.deployed_address();
+
+        let registered = Self::get_deployed_tokens(e.clone())
+            .iter()
+            .any(|token| token.address == asset_address);
+        if registered {
+            panic_with_error!(&e, TokenFactoryError::AlreadyRegistered);
+        }
+
+        // The issuer or anyone else may already have deployed the contract
+        let needs_deploy = token::TokenClient::new(&e, &asset_address).try_decimals().is_err();
+        if needs_deploy {
+            asset_deployer.deploy();
+        }
+
+        let asset_info = Self::probe_asset(&e, &asset_address);
+        let name = token::TokenClient::new(&e, &asset_address).name();
+
+        // The native XLM contract has no admin, so the deployer is recorded instead
+        let admin = match token::StellarAssetClient::new(&e, &asset_address).try_admin() {
+            Ok(Ok(admin)) => admin,
+            _ => deployer.clone(),
+        };
+
+        Self::register_token(
+            &e,
+            TokenInfo {
+                address: asset_address.clone(),
+                token_type: TokenType::StellarAsset,
+                admin,
+                timestamp: e.ledger().timestamp(),
+                name: Some(name.clone()),
+                asset: Some(asset_info),
+            },
+        );
 
         // Emit event
-        TokenDeployedEvent {
-            token_address: token_address.clone(),
-            token_type: config.token_type.clone(),
+        StellarAssetRegisteredEvent {
+            asset_address: asset_address.clone(),
             deployer: deployer.clone(),
-            name: config.name.clone(),
-            symbol: config.symbol.clone(),
-            timestamp: e.ledger().timestamp(),
+            name,
+            deployed: needs_deploy,
         }
         .publish(&e);
 
-        token_address
+        asset_address
     }
 
     /// Get all deployed tokens
@@ -574,43 +859,204 @@ impl TokenFactory {
         .publish(&e);
     }
 
-    // Helper: Get WASM hash for token type
-    fn get_wasm_for_type(e: &Env, token_type: &TokenType) -> BytesN<32> {
-        let key = match token_type {
-            TokenType::Allowlist => DataKey::AllowlistWasm,
-            TokenType::Blocklist => DataKey::BlocklistWasm,
-            TokenType::Capped => DataKey::CappedWasm,
-            TokenType::Pausable => DataKey::PausableWasm,
-            TokenType::Vault => DataKey::VaultWasm,
-        };
+    // Helper: Validate, deploy and register a token from its configuration
+    fn deploy_from_config(e: &Env, deployer: &Address, config: TokenConfig) -> Address {
+        // Get WASM hash based on token type
+        let wasm_hash = Self::get_wasm_for_type(e, &config.token_type);
 
-        e.storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(e, TokenFactoryError::WasmNotSet))
-    }
+        // Validate config based on token type
+        Self::validate_config(e, &config);
 
-    // Helper: Validate string contains no null bytes or control characters
-    fn validate_string_chars(_e: &Env, s: &String) -> bool {
-        let bytes = s.to_bytes();
-        for i in 0..bytes.len() {
-            let byte = bytes.get(i).unwrap();
-            // Check for null byte or control characters (0-31 except tab, newline, carriage return)
-            if byte == 0 || (byte < 32 && byte != 9 && byte != 10 && byte != 13) {
-                return false;
-            }
-        }
-        true
-    }
+        // Vaults must wrap a working SEP-41 token
+        let asset_info = config.asset.as_ref().map(|asset| Self::probe_asset(e, asset));
 
-    // Helper: Validate token configuration
-    fn validate_config(e: &Env, config: &TokenConfig) {
-        // Validate name (1-30 characters)
-        if config.name.len() == 0 || config.name.len() > 30 {
-            panic_with_error!(e, TokenFactoryError::InvalidName);
-        }
+        let allocations = config.allocations.clone().unwrap_or_else(|| Vec::new(e));
 
-        // Validate symbol (1-12 characters)
+        // Deploy contract - deploy_v2 requires constructor_args as Vec<Val>, not tuple
+        let token_address = match config.token_type {
+            TokenType::Capped => {
+                // Capped token requires cap parameter - safe unwrap after validation
+                let cap = config.cap.unwrap_or_else(|| {
+                    panic_with_error!(e, TokenFactoryError::MissingCap)
+                });
+
+                // Convert constructor args to Vec<Val>
+                let constructor_args: Vec<Val> = (
+                    config.admin.clone(),
+                    config.manager.clone(),
+                    config.initial_supply,
+                    cap,
+                    config.name.clone(),
+                    config.symbol.clone(),
+                    config.decimals,
+                    allocations.clone(),
+                ).into_val(e);
+
+                e.deployer()
+                    .with_address(e.current_contract_address(), config.salt)
+                    .deploy_v2(wasm_hash, constructor_args)
+            }
+            TokenType::Vault => {
+                // Vault tokens have a different constructor signature:
+                // (asset, decimals_offset, name, symbol, admin, curator, fee_recipient,
+                //  management_fee_bps, performance_fee_bps, max_fee_bps),
+                // with the manager as curator and the admin as fee recipient.
+                // Fees start at zero; the curator can raise them up to the ceiling.
+                // Validation ensures these fields are present
+                let asset = config.asset.clone().unwrap_or_else(|| {
+                    panic_with_error!(e, TokenFactoryError::InvalidConfig)
+                });
+                let decimals_offset = config.decimals_offset.unwrap_or_else(|| {
+                    panic_with_error!(e, TokenFactoryError::InvalidConfig)
+                });
+
+                // Convert constructor args to Vec<Val>
+                let constructor_args: Vec<Val> = (
+                    asset,
+                    decimals_offset,
+                    config.name.clone(),
+                    config.symbol.clone(),
+                    config.admin.clone(),
+                    config.manager.clone(),
+                    config.admin.clone(),
+                    0u32,
+                    0u32,
+                    VAULT_MAX_FEE_BPS,
+                ).into_val(e);
+
+                let vault_address = e.deployer()
+                    .with_address(e.current_contract_address(), config.salt)
+                    .deploy_v2(wasm_hash, constructor_args);
+
+                // Seed the vault from the deployer, minting the first shares to
+                // the factory, which never moves them. These dead shares make a
+                // donation-based share inflation attack on the first depositor
+                // unprofitable.
+                if config.initial_supply > 0 {
+                    VaultClient::new(e, &vault_address).deposit(
+                        &config.initial_supply,
+                        &e.current_contract_address(),
+                        deployer,
+                        deployer,
+                    );
+                }
+
+                vault_address
+            }
+            TokenType::Composable => {
+                // Composable tokens append the feature bitmask and feature parameters
+                // to the standard constructor
+                let features = config.features.unwrap_or_else(|| {
+                    panic_with_error!(e, TokenFactoryError::InvalidFeatures)
+                });
+                let feature_config = FeatureConfig { cap: config.cap };
+
+                // Convert constructor args to Vec<Val>
+                let constructor_args: Vec<Val> = (
+                    config.admin.clone(),
+                    config.manager.clone(),
+                    config.initial_supply,
+                    config.name.clone(),
+                    config.symbol.clone(),
+                    config.decimals,
+                    features,
+                    feature_config,
+                    allocations.clone(),
+                ).into_val(e);
+
+                e.deployer()
+                    .with_address(e.current_contract_address(), config.salt)
+                    .deploy_v2(wasm_hash, constructor_args)
+            }
+            _ => {
+                // Other token types use standard constructor
+                // Convert constructor args to Vec<Val>
+                let constructor_args: Vec<Val> = (
+                    config.admin.clone(),
+                    config.manager.clone(),
+                    config.initial_supply,
+                    config.name.clone(),
+                    config.symbol.clone(),
+                    config.decimals,
+                    allocations.clone(),
+                ).into_val(e);
+
+                e.deployer()
+                    .with_address(e.current_contract_address(), config.salt)
+                    .deploy_v2(wasm_hash, constructor_args)
+            }
+        };
+
+        // Update state AFTER successful deployment
+        Self::register_token(
+            e,
+            TokenInfo {
+                address: token_address.clone(),
+                token_type: config.token_type.clone(),
+                admin: config.admin.clone(),
+                timestamp: e.ledger().timestamp(),
+                name: Some(config.name.clone()),
+                asset: asset_info,
+            },
+        );
+
+        // Emit event
+        TokenDeployedEvent {
+            token_address: token_address.clone(),
+            token_type: config.token_type.clone(),
+            deployer: deployer.clone(),
+            name: config.name.clone(),
+            symbol: config.symbol.clone(),
+            timestamp: e.ledger().timestamp(),
+            allocations,
+        }
+        .publish(e);
+
+        token_address
+    }
+
+    // Helper: Get WASM hash for token type
+    fn get_wasm_for_type(e: &Env, token_type: &TokenType) -> BytesN<32> {
+        let key = match token_type {
+            TokenType::Allowlist => DataKey::AllowlistWasm,
+            TokenType::Blocklist => DataKey::BlocklistWasm,
+            TokenType::Capped => DataKey::CappedWasm,
+            TokenType::Pausable => DataKey::PausableWasm,
+            TokenType::Vault => DataKey::VaultWasm,
+            TokenType::Composable => DataKey::ComposableWasm,
+            TokenType::Vesting => DataKey::VestingWasm,
+            TokenType::Airdrop => DataKey::AirdropWasm,
+            // Stellar Asset Contracts are built into the network
+            TokenType::StellarAsset => panic_with_error!(e, TokenFactoryError::InvalidTokenType),
+        };
+
+        e.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(e, TokenFactoryError::WasmNotSet))
+    }
+
+    // Helper: Validate string contains no null bytes or control characters
+    fn validate_string_chars(_e: &Env, s: &String) -> bool {
+        let bytes = s.to_bytes();
+        for i in 0..bytes.len() {
+            let byte = bytes.get(i).unwrap();
+            // Check for null byte or control characters (0-31 except tab, newline, carriage return)
+            if byte == 0 || (byte < 32 && byte != 9 && byte != 10 && byte != 13) {
+                return false;
+            }
+        }
+        true
+    }
+
+    // Helper: Validate token configuration
+    fn validate_config(e: &Env, config: &TokenConfig) {
+        // Validate name (1-30 characters)
+        if config.name.len() == 0 || config.name.len() > 30 {
+            panic_with_error!(e, TokenFactoryError::InvalidName);
+        }
+
+        // Validate symbol (1-12 characters)
         if config.symbol.len() == 0 || config.symbol.len() > 12 {
             panic_with_error!(e, TokenFactoryError::InvalidSymbol);
         }
@@ -634,13 +1080,24 @@ impl TokenFactory {
         }
 
         // Check for unreasonably large supply (to prevent overflow issues)
-        const MAX_SUPPLY: i128 = i128::MAX / 2; // Safe upper bound
         if config.initial_supply > MAX_SUPPLY {
             panic_with_error!(e, TokenFactoryError::SupplyTooLarge);
         }
 
+        // Supply minted at deployment, including initial allocations
+        let total_supply = Self::validate_allocations(e, config);
+
+        // Feature flags only apply to Composable tokens
+        if config.token_type != TokenType::Composable && config.features.is_some() {
+            panic_with_error!(e, TokenFactoryError::InvalidConfig);
+        }
+
         // Type-specific validation
         match config.token_type {
+            TokenType::Vesting | TokenType::Airdrop | TokenType::StellarAsset => {
+                // Vesting wallets, airdrops and Stellar assets have dedicated deploy functions
+                panic_with_error!(e, TokenFactoryError::InvalidTokenType);
+            }
             TokenType::Capped => {
                 // Capped tokens must have a cap
                 if config.cap.is_none() {
@@ -648,7 +1105,7 @@ impl TokenFactory {
                 }
                 // Initial supply must not exceed cap
                 if let Some(cap) = config.cap {
-                    if config.initial_supply > cap {
+                    if total_supply > cap {
                         panic_with_error!(e, TokenFactoryError::CapTooLow);
                     }
                     // Cap must also be reasonable
@@ -673,6 +1130,41 @@ impl TokenFactory {
                 if config.cap.is_some() {
                     panic_with_error!(e, TokenFactoryError::UnexpectedCap);
                 }
+                // Vault shares are only minted against deposits
+                if config.allocations.is_some() {
+                    panic_with_error!(e, TokenFactoryError::InvalidConfig);
+                }
+            }
+            TokenType::Composable => {
+                let features = config.features.unwrap_or_else(|| {
+                    panic_with_error!(e, TokenFactoryError::InvalidFeatures)
+                });
+                // Only known feature flags may be set
+                if features & !ALL_FEATURES != 0 {
+                    panic_with_error!(e, TokenFactoryError::InvalidFeatures);
+                }
+                // A token is either allowlisted or blocklisted, never both
+                if features & FEATURE_ALLOWLIST != 0 && features & FEATURE_BLOCKLIST != 0 {
+                    panic_with_error!(e, TokenFactoryError::ConflictingFeatures);
+                }
+                // Cap must be present exactly when the capped feature is enabled
+                if features & FEATURE_CAPPED != 0 {
+                    let cap = config.cap.unwrap_or_else(|| {
+                        panic_with_error!(e, TokenFactoryError::MissingCap)
+                    });
+                    if total_supply > cap {
+                        panic_with_error!(e, TokenFactoryError::CapTooLow);
+                    }
+                    if cap > MAX_SUPPLY {
+                        panic_with_error!(e, TokenFactoryError::SupplyTooLarge);
+                    }
+                } else if config.cap.is_some() {
+                    panic_with_error!(e, TokenFactoryError::UnexpectedCap);
+                }
+                // Vault-specific fields should not be set for Composable tokens
+                if config.asset.is_some() || config.decimals_offset.is_some() {
+                    panic_with_error!(e, TokenFactoryError::InvalidConfig);
+                }
             }
             _ => {
                 // Non-capped, non-vault tokens should not have cap or vault-specific fields
@@ -686,6 +1178,138 @@ impl TokenFactory {
         }
     }
 
+    // Helper: Read an asset's metadata through SEP-41 calls, rejecting
+    // accounts and contracts that do not behave as tokens
+    fn probe_asset(e: &Env, asset: &Address) -> AssetInfo {
+        let client = token::TokenClient::new(e, asset);
+
+        let decimals = match client.try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => panic_with_error!(e, TokenFactoryError::InvalidAsset),
+        };
+        let symbol = match client.try_symbol() {
+            Ok(Ok(symbol)) => symbol,
+            _ => panic_with_error!(e, TokenFactoryError::InvalidAsset),
+        };
+        if !matches!(client.try_balance(&e.current_contract_address()), Ok(Ok(_))) {
+            panic_with_error!(e, TokenFactoryError::InvalidAsset);
+        }
+
+        AssetInfo {
+            address: asset.clone(),
+            symbol,
+            decimals,
+        }
+    }
+
+    // Helper: Validate initial allocations and return the total supply minted at deployment
+    fn validate_allocations(e: &Env, config: &TokenConfig) -> i128 {
+        let mut total = config.initial_supply;
+        let Some(allocations) = &config.allocations else {
+            return total;
+        };
+
+        if allocations.is_empty() || allocations.len() > MAX_ALLOCATIONS {
+            panic_with_error!(e, TokenFactoryError::InvalidAllocation);
+        }
+        for (_, amount) in allocations.iter() {
+            if amount <= 0 {
+                panic_with_error!(e, TokenFactoryError::InvalidAllocation);
+            }
+            total = total
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(e, TokenFactoryError::SupplyTooLarge));
+        }
+
+        if total > MAX_SUPPLY {
+            panic_with_error!(e, TokenFactoryError::SupplyTooLarge);
+        }
+        total
+    }
+
+    // Helper: Validate vesting configuration and return the total amount to lock
+    fn validate_vesting_config(e: &Env, config: &VestingConfig) -> i128 {
+        if config.schedules.is_empty() {
+            panic_with_error!(e, TokenFactoryError::InvalidSchedule);
+        }
+
+        let mut total: i128 = 0;
+        for schedule in config.schedules.iter() {
+            if schedule.amount <= 0 {
+                panic_with_error!(e, TokenFactoryError::InvalidSchedule);
+            }
+            // Cliff must fall within a non-empty vesting period
+            if schedule.duration_seconds == 0 || schedule.cliff_seconds > schedule.duration_seconds {
+                panic_with_error!(e, TokenFactoryError::InvalidSchedule);
+            }
+            if schedule.start.checked_add(schedule.duration_seconds).is_none() {
+                panic_with_error!(e, TokenFactoryError::InvalidSchedule);
+            }
+            total = total
+                .checked_add(schedule.amount)
+                .unwrap_or_else(|| panic_with_error!(e, TokenFactoryError::SupplyTooLarge));
+        }
+
+        if total > MAX_SUPPLY {
+            panic_with_error!(e, TokenFactoryError::SupplyTooLarge);
+        }
+        total
+    }
+
+    // Helper: Validate airdrop configuration (the token itself is validated on deployment)
+    fn validate_airdrop_config(e: &Env, config: &AirdropConfig) {
+        // Claims must be possible at least until the next ledger
+        if config.deadline <= e.ledger().timestamp() {
+            panic_with_error!(e, TokenFactoryError::InvalidAirdrop);
+        }
+
+        // There must be something to distribute. Allocations are minted to their
+        // recipients alongside the airdropped supply.
+        if config.token.initial_supply <= 0 {
+            panic_with_error!(e, TokenFactoryError::InvalidAirdrop);
+        }
+
+        // The distributor and claimants must be able to hold the token, which
+        // rules out vaults and allowlisted tokens
+        match config.token.token_type {
+            TokenType::Allowlist | TokenType::Vault => {
+                panic_with_error!(e, TokenFactoryError::InvalidAirdrop);
+            }
+            TokenType::Composable => {
+                let features = config.token.features.unwrap_or(0);
+                if features & FEATURE_ALLOWLIST != 0 {
+                    panic_with_error!(e, TokenFactoryError::InvalidAirdrop);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Helper: Record a deployed contract in the registry
+    fn register_token(e: &Env, token_info: TokenInfo) {
+        // Increment token count with overflow protection
+        let count: u32 = e.storage().instance().get(&DataKey::TokenCount).unwrap_or(0);
+        let new_count = count.checked_add(1)
+            .unwrap_or_else(|| {
+                panic_with_error!(e, TokenFactoryError::CounterOverflow)
+            });
+
+        let mut tokens: Vec<TokenInfo> = e
+            .storage()
+            .instance()
+            .get(&DataKey::DeployedTokens)
+            .unwrap_or_else(|| Vec::new(e));
+        tokens.push_back(token_info);
+        e.storage()
+            .instance()
+            .set(&DataKey::DeployedTokens, &tokens);
+
+        // Update token count
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenCount, &new_count);
+    }
+
     // Helper: Check admin authorization
     fn require_admin(e: &Env, address: &Address) {
         let admin: Address = e
@@ -723,6 +1347,9 @@ mod test {
         client.set_capped_wasm(&admin, &wasm_hash);
         client.set_pausable_wasm(&admin, &wasm_hash);
         client.set_vault_wasm(&admin, &wasm_hash);
+        client.set_composable_wasm(&admin, &wasm_hash);
+        client.set_vesting_wasm(&admin, &wasm_hash);
+        client.set_airdrop_wasm(&admin, &wasm_hash);
 
         (client, admin, wasm_hash)
     }
@@ -766,6 +1393,9 @@ mod test {
         client.set_capped_wasm(&admin, &wasm_hash);
         client.set_pausable_wasm(&admin, &wasm_hash);
         client.set_vault_wasm(&admin, &wasm_hash);
+        client.set_composable_wasm(&admin, &wasm_hash);
+        client.set_vesting_wasm(&admin, &wasm_hash);
+        client.set_airdrop_wasm(&admin, &wasm_hash);
     }
 
     #[test]
@@ -806,6 +1436,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -829,6 +1461,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -852,6 +1486,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -875,6 +1511,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -901,6 +1539,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -927,6 +1567,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -950,6 +1592,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -973,6 +1617,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -996,6 +1642,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1019,6 +1667,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1042,6 +1692,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1067,6 +1719,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: None, // Missing asset for Vault
             decimals_offset: Some(2),
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1091,6 +1745,8 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: Some(asset),
             decimals_offset: None, // Missing decimals_offset for Vault
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1115,6 +1771,60 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: Some(asset),
             decimals_offset: Some(2),
+            features: None,
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #23)")] // InvalidAsset - account address as asset
+    fn test_validation_vault_account_asset() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let asset = Address::generate(&env);
+        let config = TokenConfig {
+            token_type: TokenType::Vault,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 0,
+            cap: None,
+            name: String::from_str(&env, "Vault Token"),
+            symbol: String::from_str(&env, "VLT"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: Some(asset), // Not a contract
+            decimals_offset: Some(2),
+            features: None,
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #23)")] // InvalidAsset - non-token contract as asset
+    fn test_validation_vault_non_token_asset() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let asset = env.register(TokenFactory, (&admin,));
+        let config = TokenConfig {
+            token_type: TokenType::Vault,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 0,
+            cap: None,
+            name: String::from_str(&env, "Vault Token"),
+            symbol: String::from_str(&env, "VLT"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: Some(asset), // A contract without the SEP-41 interface
+            decimals_offset: Some(2),
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1139,6 +1849,615 @@ mod test {
             salt: BytesN::from_array(&env, &[2u8; 32]),
             asset: Some(asset), // Allowlist should not have vault fields
             decimals_offset: Some(2),
+            features: None,
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    // ===== Composable Validation Tests =====
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #19)")] // InvalidFeatures - missing features
+    fn test_validation_composable_missing_features() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Composable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: None,
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: None, // Missing features for Composable
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #19)")] // InvalidFeatures - unknown flag
+    fn test_validation_composable_unknown_feature() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Composable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: None,
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: Some(FEATURE_PAUSABLE | (1 << 10)),
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #20)")] // ConflictingFeatures
+    fn test_validation_composable_allowlist_with_blocklist() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Composable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: None,
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: Some(FEATURE_ALLOWLIST | FEATURE_BLOCKLIST),
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #9)")] // MissingCap
+    fn test_validation_composable_capped_without_cap() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Composable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: None, // Missing cap while FEATURE_CAPPED is set
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: Some(FEATURE_CAPPED | FEATURE_PAUSABLE | FEATURE_BLOCKLIST),
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")] // CapTooLow
+    fn test_validation_composable_cap_too_low() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Composable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 2000000,
+            cap: Some(1000000), // Cap less than initial supply
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: Some(FEATURE_CAPPED | FEATURE_MINTABLE),
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #11)")] // UnexpectedCap
+    fn test_validation_composable_cap_without_capped_feature() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Composable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: Some(2000000), // Cap without FEATURE_CAPPED
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: Some(FEATURE_PAUSABLE),
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")] // InvalidConfig - non-Composable with features
+    fn test_validation_pausable_with_features() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Pausable,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: None,
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: Some(FEATURE_BURNABLE), // Only Composable tokens take features
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    // ===== Allocation Validation Tests =====
+
+    fn allocation_config(env: &Env, admin: &Address, allocations: Vec<(Address, i128)>) -> TokenConfig {
+        TokenConfig {
+            token_type: TokenType::Capped,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 1000000,
+            cap: Some(2000000),
+            name: String::from_str(env, "Test Token"),
+            symbol: String::from_str(env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: None,
+            allocations: Some(allocations),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")] // CapTooLow
+    fn test_validation_allocations_exceed_cap() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        // Initial supply fits the cap, but not together with the allocations
+        let mut allocations = Vec::new(&env);
+        allocations.push_back((Address::generate(&env), 600000));
+        allocations.push_back((Address::generate(&env), 400001));
+
+        client.deploy_token(&admin, &allocation_config(&env, &admin, allocations));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #24)")] // InvalidAllocation
+    fn test_validation_zero_allocation() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut allocations = Vec::new(&env);
+        allocations.push_back((Address::generate(&env), 0));
+
+        client.deploy_token(&admin, &allocation_config(&env, &admin, allocations));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #24)")] // InvalidAllocation
+    fn test_validation_empty_allocations() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        client.deploy_token(&admin, &allocation_config(&env, &admin, Vec::new(&env)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #24)")] // InvalidAllocation
+    fn test_validation_too_many_allocations() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut allocations = Vec::new(&env);
+        for _ in 0..=MAX_ALLOCATIONS {
+            allocations.push_back((Address::generate(&env), 1));
+        }
+
+        client.deploy_token(&admin, &allocation_config(&env, &admin, allocations));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #15)")] // SupplyTooLarge
+    fn test_validation_allocations_overflow() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut allocations = Vec::new(&env);
+        allocations.push_back((Address::generate(&env), i128::MAX));
+
+        let mut config = allocation_config(&env, &admin, allocations);
+        config.cap = None;
+        config.token_type = TokenType::Pausable;
+        client.deploy_token(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")] // InvalidConfig - Vault with allocations
+    fn test_validation_vault_with_allocations() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut allocations = Vec::new(&env);
+        allocations.push_back((Address::generate(&env), 100));
+
+        let mut config = allocation_config(&env, &admin, allocations);
+        config.token_type = TokenType::Vault;
+        config.cap = None;
+        config.initial_supply = 0;
+        config.asset = Some(Address::generate(&env));
+        config.decimals_offset = Some(2);
+        client.deploy_token(&admin, &config);
+    }
+
+    // ===== Vesting Validation Tests =====
+
+    fn vesting_schedule(env: &Env, amount: i128, cliff_seconds: u64, duration_seconds: u64) -> VestingParams {
+        VestingParams {
+            beneficiary: Address::generate(env),
+            amount,
+            start: 1000,
+            cliff_seconds,
+            duration_seconds,
+            revocable: true,
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")] // InvalidSchedule - no schedules
+    fn test_validation_vesting_empty_schedules() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = VestingConfig {
+            admin: admin.clone(),
+            token: Address::generate(&env),
+            schedules: Vec::new(&env),
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+        };
+
+        client.deploy_vesting(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")] // InvalidSchedule - zero amount
+    fn test_validation_vesting_zero_amount() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = VestingConfig {
+            admin: admin.clone(),
+            token: Address::generate(&env),
+            schedules: Vec::from_array(&env, [vesting_schedule(&env, 0, 100, 1000)]),
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+        };
+
+        client.deploy_vesting(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #21)")] // InvalidSchedule - cliff after end
+    fn test_validation_vesting_cliff_exceeds_duration() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = VestingConfig {
+            admin: admin.clone(),
+            token: Address::generate(&env),
+            schedules: Vec::from_array(
+                &env,
+                [vesting_schedule(&env, 1000, 100, 1000), vesting_schedule(&env, 1000, 2000, 1000)],
+            ),
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+        };
+
+        client.deploy_vesting(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #15)")] // SupplyTooLarge - total overflows
+    fn test_validation_vesting_total_too_large() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = VestingConfig {
+            admin: admin.clone(),
+            token: Address::generate(&env),
+            schedules: Vec::from_array(
+                &env,
+                [
+                    vesting_schedule(&env, i128::MAX / 2, 0, 1000),
+                    vesting_schedule(&env, i128::MAX / 2, 0, 1000),
+                ],
+            ),
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+        };
+
+        client.deploy_vesting(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #3)")] // InvalidTokenType
+    fn test_deploy_token_rejects_vesting_type() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::Vesting,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 0,
+            cap: None,
+            name: String::from_str(&env, "Vesting"),
+            symbol: String::from_str(&env, "VEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: None,
+            allocations: None,
+        };
+
+        client.deploy_token(&admin, &config);
+    }
+
+    // ===== Airdrop Validation Tests =====
+
+    fn airdrop_config(env: &Env, admin: &Address, token_type: TokenType, initial_supply: i128) -> AirdropConfig {
+        AirdropConfig {
+            admin: admin.clone(),
+            token: TokenConfig {
+                token_type,
+                admin: admin.clone(),
+                manager: admin.clone(),
+                initial_supply,
+                cap: None,
+                name: String::from_str(env, "Airdrop Token"),
+                symbol: String::from_str(env, "DROP"),
+                decimals: 7,
+                salt: BytesN::from_array(env, &[3u8; 32]),
+                asset: None,
+                decimals_offset: None,
+                features: None,
+                allocations: None,
+            },
+            root_hash: BytesN::from_array(env, &[0u8; 32]),
+            deadline: env.ledger().timestamp() + 1000,
+            salt: BytesN::from_array(env, &[4u8; 32]),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #22)")] // InvalidAirdrop - nothing to distribute
+    fn test_validation_airdrop_zero_supply() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = airdrop_config(&env, &admin, TokenType::Blocklist, 0);
+        client.deploy_airdrop(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #22)")] // InvalidAirdrop - deadline already passed
+    fn test_validation_airdrop_past_deadline() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut config = airdrop_config(&env, &admin, TokenType::Blocklist, 1000);
+        config.deadline = env.ledger().timestamp();
+        client.deploy_airdrop(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #22)")] // InvalidAirdrop - allowlisted token
+    fn test_validation_airdrop_allowlist_token() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = airdrop_config(&env, &admin, TokenType::Allowlist, 1000);
+        client.deploy_airdrop(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #22)")] // InvalidAirdrop - composable with allowlist
+    fn test_validation_airdrop_composable_allowlist_token() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut config = airdrop_config(&env, &admin, TokenType::Composable, 1000);
+        config.token.features = Some(FEATURE_ALLOWLIST | FEATURE_BURNABLE);
+        client.deploy_airdrop(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")] // InvalidName - token config still validated
+    fn test_validation_airdrop_invalid_token_config() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let mut config = airdrop_config(&env, &admin, TokenType::Pausable, 1000);
+        config.token.name = String::from_str(&env, "");
+        client.deploy_airdrop(&admin, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #24)")] // InvalidAllocation
+    fn test_validation_airdrop_allocations_count_distributor() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        // The airdropped supply takes one of the allocation slots
+        let mut allocations = Vec::new(&env);
+        for _ in 0..MAX_ALLOCATIONS {
+            allocations.push_back((Address::generate(&env), 1));
+        }
+        let mut config = airdrop_config(&env, &admin, TokenType::Blocklist, 1000);
+        config.token.allocations = Some(allocations);
+        client.deploy_airdrop(&admin, &config);
+    }
+
+    // ===== Stellar Asset Tests =====
+
+    fn serialized_asset(env: &Env, code: &[u8; 4], issuer: [u8; 32]) -> Bytes {
+        use soroban_sdk::xdr::{
+            AccountId, AlphaNum4, Asset, AssetCode4, Limits, PublicKey, Uint256, WriteXdr,
+        };
+
+        let asset = Asset::CreditAlphanum4(AlphaNum4 {
+            asset_code: AssetCode4(*code),
+            issuer: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(issuer))),
+        });
+        Bytes::from_slice(env, &asset.to_xdr(Limits::none()).unwrap())
+    }
+
+    #[test]
+    fn test_deploy_stellar_asset() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let asset = serialized_asset(&env, b"USDC", [7u8; 32]);
+        let asset_address = client.deploy_stellar_asset(&admin, &asset);
+
+        let token = token::TokenClient::new(&env, &asset_address);
+        assert_eq!(token.symbol(), String::from_str(&env, "USDC"));
+        assert_eq!(token.decimals(), 7);
+
+        let tokens = client.get_tokens_by_type(&TokenType::StellarAsset);
+        assert_eq!(tokens.len(), 1);
+        let info = tokens.get(0).unwrap();
+        assert_eq!(info.address, asset_address);
+        assert_eq!(info.name, Some(token.name()));
+        let asset_info = info.asset.unwrap();
+        assert_eq!(asset_info.symbol, String::from_str(&env, "USDC"));
+        assert_eq!(asset_info.decimals, 7);
+    }
+
+    #[test]
+    fn test_register_existing_stellar_asset() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+        let (other_client, other_admin, _) = setup_with_wasm(&env);
+
+        // The first factory deploys the contract, the second one finds it
+        let asset = serialized_asset(&env, b"EURC", [9u8; 32]);
+        let asset_address = client.deploy_stellar_asset(&admin, &asset);
+        assert_eq!(other_client.deploy_stellar_asset(&other_admin, &asset), asset_address);
+        assert_eq!(other_client.get_tokens_by_type(&TokenType::StellarAsset).len(), 1);
+    }
+
+    #[test]
+    fn test_deploy_native_stellar_asset() {
+        use soroban_sdk::xdr::{Asset, Limits, WriteXdr};
+
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let asset = Bytes::from_slice(&env, &Asset::Native.to_xdr(Limits::none()).unwrap());
+        let asset_address = client.deploy_stellar_asset(&admin, &asset);
+
+        let token = token::TokenClient::new(&env, &asset_address);
+        assert_eq!(token.symbol(), String::from_str(&env, "native"));
+
+        // XLM has no admin, the deployer is recorded instead
+        let info = client.get_tokens_by_type(&TokenType::StellarAsset).get(0).unwrap();
+        assert_eq!(info.address, asset_address);
+        assert_eq!(info.admin, admin);
+    }
+
+    #[test]
+    fn test_register_stellar_asset_deployed_elsewhere() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        // Deployed outside any factory, with the admin handed over by the issuer
+        let asset = serialized_asset(&env, b"GBPC", [5u8; 32]);
+        let asset_address = env.deployer().with_stellar_asset(asset.clone()).deploy();
+        let asset_admin = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &asset_address).set_admin(&asset_admin);
+
+        assert_eq!(client.deploy_stellar_asset(&admin, &asset), asset_address);
+
+        let info = client.get_tokens_by_type(&TokenType::StellarAsset).get(0).unwrap();
+        assert_eq!(info.admin, asset_admin);
+        assert_eq!(info.name, Some(token::TokenClient::new(&env, &asset_address).name()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #25)")] // AlreadyRegistered
+    fn test_deploy_stellar_asset_twice() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let asset = serialized_asset(&env, b"USDC", [7u8; 32]);
+        client.deploy_stellar_asset(&admin, &asset);
+        client.deploy_stellar_asset(&admin, &asset);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #3)")] // InvalidTokenType
+    fn test_deploy_token_rejects_stellar_asset_type() {
+        let env = Env::default();
+        let (client, admin, _) = setup_with_wasm(&env);
+
+        let config = TokenConfig {
+            token_type: TokenType::StellarAsset,
+            admin: admin.clone(),
+            manager: admin.clone(),
+            initial_supply: 0,
+            cap: None,
+            name: String::from_str(&env, "Test Token"),
+            symbol: String::from_str(&env, "TEST"),
+            decimals: 7,
+            salt: BytesN::from_array(&env, &[2u8; 32]),
+            asset: None,
+            decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&admin, &config);
@@ -1231,6 +2550,8 @@ mod test {
             salt: salt.clone(),
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         // First deployment should succeed
@@ -1249,6 +2570,8 @@ mod test {
             salt: salt.clone(), // Same salt!
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&deployer, &config2); // Should panic with DuplicateSalt
@@ -1282,6 +2605,8 @@ mod test {
                 salt,
                 asset: None,
                 decimals_offset: None,
+                features: None,
+                allocations: None,
             };
             client.deploy_token(&deployer, &config);
         }
@@ -1300,6 +2625,8 @@ mod test {
             salt,
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&deployer, &config); // Should panic
@@ -1334,6 +2661,8 @@ mod test {
             salt,
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&deployer, &config); // Should panic
@@ -1371,6 +2700,8 @@ mod test {
             salt,
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         let result = client.deploy_token(&deployer, &config);
@@ -1516,6 +2847,8 @@ mod test {
             salt,
             asset: None,
             decimals_offset: None,
+            features: None,
+            allocations: None,
         };
 
         client.deploy_token(&deployer, &config);
@@ -1605,6 +2938,8 @@ mod test {
                 salt,
                 asset: None,
                 decimals_offset: None,
+                features: None,
+                allocations: None,
             };
             client.deploy_token(&deployer, &config);
         }
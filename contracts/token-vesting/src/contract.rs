@@ -0,0 +1,275 @@
+//! # Token Vesting Contract Example
+//!
+//! This contract locks SEP-41 tokens for a set of beneficiaries and releases
+//! them linearly over time. Each beneficiary has a single schedule made of:
+//!
+//! - `start: u64` — ledger timestamp at which vesting begins
+//! - `cliff_seconds: u64` — nothing is releasable before `start + cliff_seconds`
+//! - `duration_seconds: u64` — everything is releasable from `start + duration_seconds`
+//! - `revocable: bool` — whether the admin may stop the schedule early
+//!
+//! Anyone can call `release(beneficiary)`; vested tokens are always paid to
+//! the beneficiary. When a revocable schedule is revoked, tokens vested so far
+//! stay claimable and the unvested remainder is returned to the admin.
+//!
+//! Schedules passed to the constructor are expected to be funded right after
+//! deployment (TokenFactory does this in the same call). Schedules added later
+//! with `add_schedule` are funded from the admin.
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, token,
+    Address, Env, Vec, I256,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingParams {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub start: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    pub revocable: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub total_amount: i128,
+    pub released: i128,
+    pub start: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    pub revocable: bool,
+    pub revoked: bool,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Beneficiaries,
+    Schedule(Address),
+}
+
+#[contractevent]
+pub struct ScheduleCreatedEvent {
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub start: u64,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+}
+
+#[contractevent]
+pub struct TokensReleasedEvent {
+    pub beneficiary: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct ScheduleRevokedEvent {
+    pub beneficiary: Address,
+    pub refund: i128,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VestingError {
+    InvalidSchedule = 1,
+    ScheduleExists = 2,
+    ScheduleNotFound = 3,
+    NotRevocable = 4,
+    AlreadyRevoked = 5,
+    NothingToRelease = 6,
+}
+
+#[contract]
+pub struct TokenVesting;
+
+#[contractimpl]
+impl TokenVesting {
+    pub fn __constructor(e: &Env, admin: Address, token: Address, schedules: Vec<VestingParams>) {
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::Token, &token);
+        e.storage().instance().set(&DataKey::Beneficiaries, &Vec::<Address>::new(e));
+
+        for params in schedules.iter() {
+            create_schedule(e, &params);
+        }
+    }
+
+    /// Adds a schedule for a new beneficiary, funded from the admin.
+    pub fn add_schedule(e: &Env, params: VestingParams) {
+        let admin = get_admin(e);
+        admin.require_auth();
+
+        create_schedule(e, &params);
+        token::Client::new(e, &get_token(e)).transfer(
+            &admin,
+            e.current_contract_address(),
+            &params.amount,
+        );
+    }
+
+    /// Pays the vested but unreleased tokens to `beneficiary`.
+    ///
+    /// # Returns
+    /// Amount released
+    pub fn release(e: &Env, beneficiary: Address) -> i128 {
+        let mut schedule = get_schedule(e, &beneficiary);
+        let amount = vested_at(e, &schedule, e.ledger().timestamp()) - schedule.released;
+        if amount <= 0 {
+            panic_with_error!(e, VestingError::NothingToRelease);
+        }
+
+        schedule.released += amount;
+        e.storage().persistent().set(&DataKey::Schedule(beneficiary.clone()), &schedule);
+        token::Client::new(e, &get_token(e)).transfer(
+            &e.current_contract_address(),
+            &beneficiary,
+            &amount,
+        );
+
+        TokensReleasedEvent { beneficiary, amount }.publish(e);
+        amount
+    }
+
+    /// Stops a revocable schedule and returns the unvested tokens to the admin.
+    ///
+    /// # Returns
+    /// Amount refunded to the admin
+    pub fn revoke(e: &Env, beneficiary: Address) -> i128 {
+        let admin = get_admin(e);
+        admin.require_auth();
+
+        let mut schedule = get_schedule(e, &beneficiary);
+        if !schedule.revocable {
+            panic_with_error!(e, VestingError::NotRevocable);
+        }
+        if schedule.revoked {
+            panic_with_error!(e, VestingError::AlreadyRevoked);
+        }
+
+        // Whatever has vested so far remains claimable by the beneficiary
+        let vested = vested_at(e, &schedule, e.ledger().timestamp());
+        let refund = schedule.total_amount - vested;
+        schedule.total_amount = vested;
+        schedule.revoked = true;
+        e.storage().persistent().set(&DataKey::Schedule(beneficiary.clone()), &schedule);
+
+        if refund > 0 {
+            token::Client::new(e, &get_token(e)).transfer(
+                &e.current_contract_address(),
+                &admin,
+                &refund,
+            );
+        }
+
+        ScheduleRevokedEvent { beneficiary, refund }.publish(e);
+        refund
+    }
+
+    /// Returns the amount `beneficiary` could release right now.
+    pub fn releasable(e: &Env, beneficiary: Address) -> i128 {
+        let schedule = get_schedule(e, &beneficiary);
+        vested_at(e, &schedule, e.ledger().timestamp()) - schedule.released
+    }
+
+    /// Returns the amount vested for `beneficiary` at `timestamp`.
+    pub fn vested_amount(e: &Env, beneficiary: Address, timestamp: u64) -> i128 {
+        vested_at(e, &get_schedule(e, &beneficiary), timestamp)
+    }
+
+    pub fn get_schedule(e: &Env, beneficiary: Address) -> VestingSchedule {
+        get_schedule(e, &beneficiary)
+    }
+
+    pub fn beneficiaries(e: &Env) -> Vec<Address> {
+        e.storage().instance().get(&DataKey::Beneficiaries).unwrap_or(Vec::new(e))
+    }
+
+    pub fn token(e: &Env) -> Address {
+        get_token(e)
+    }
+
+    pub fn admin(e: &Env) -> Address {
+        get_admin(e)
+    }
+}
+
+fn create_schedule(e: &Env, params: &VestingParams) {
+    if params.amount <= 0
+        || params.duration_seconds == 0
+        || params.cliff_seconds > params.duration_seconds
+        || params.start.checked_add(params.duration_seconds).is_none()
+    {
+        panic_with_error!(e, VestingError::InvalidSchedule);
+    }
+
+    let key = DataKey::Schedule(params.beneficiary.clone());
+    if e.storage().persistent().has(&key) {
+        panic_with_error!(e, VestingError::ScheduleExists);
+    }
+
+    let schedule = VestingSchedule {
+        total_amount: params.amount,
+        released: 0,
+        start: params.start,
+        cliff_seconds: params.cliff_seconds,
+        duration_seconds: params.duration_seconds,
+        revocable: params.revocable,
+        revoked: false,
+    };
+    e.storage().persistent().set(&key, &schedule);
+
+    let mut beneficiaries: Vec<Address> =
+        e.storage().instance().get(&DataKey::Beneficiaries).unwrap_or(Vec::new(e));
+    beneficiaries.push_back(params.beneficiary.clone());
+    e.storage().instance().set(&DataKey::Beneficiaries, &beneficiaries);
+
+    ScheduleCreatedEvent {
+        beneficiary: params.beneficiary.clone(),
+        amount: params.amount,
+        start: params.start,
+        cliff_seconds: params.cliff_seconds,
+        duration_seconds: params.duration_seconds,
+    }
+    .publish(e);
+}
+
+// Linear vesting with a cliff. Revoked schedules are frozen at the amount
+// vested when they were revoked.
+fn vested_at(e: &Env, schedule: &VestingSchedule, timestamp: u64) -> i128 {
+    if schedule.revoked {
+        return schedule.total_amount;
+    }
+    if timestamp < schedule.start + schedule.cliff_seconds {
+        return 0;
+    }
+    let elapsed = timestamp - schedule.start;
+    if elapsed >= schedule.duration_seconds {
+        return schedule.total_amount;
+    }
+    // The product can exceed i128 for large amounts, the quotient cannot
+    I256::from_i128(e, schedule.total_amount)
+        .mul(&I256::from_i128(e, elapsed as i128))
+        .div(&I256::from_i128(e, schedule.duration_seconds as i128))
+        .to_i128()
+        .expect("vested amount is at most the total amount")
+}
+
+fn get_schedule(e: &Env, beneficiary: &Address) -> VestingSchedule {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Schedule(beneficiary.clone()))
+        .unwrap_or_else(|| panic_with_error!(e, VestingError::ScheduleNotFound))
+}
+
+fn get_admin(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).expect("admin should be set")
+}
+
+fn get_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token).expect("token should be set")
+}
@@ -0,0 +1,234 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+use crate::contract::{TokenVesting, TokenVestingClient, VestingParams};
+
+const START: u64 = 1_000;
+const CLIFF: u64 = 100;
+const DURATION: u64 = 1_000;
+
+fn create_token<'a>(e: &Env, admin: &Address) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    (token::Client::new(e, &sac.address()), token::StellarAssetClient::new(e, &sac.address()))
+}
+
+fn params(beneficiary: &Address, amount: i128, revocable: bool) -> VestingParams {
+    VestingParams {
+        beneficiary: beneficiary.clone(),
+        amount,
+        start: START,
+        cliff_seconds: CLIFF,
+        duration_seconds: DURATION,
+        revocable,
+    }
+}
+
+// Deploys a vesting contract with the given schedules and funds it, mirroring
+// what TokenFactory does in `deploy_vesting`.
+fn create_vesting<'a>(
+    e: &Env,
+    admin: &Address,
+    token: &token::Client,
+    asset: &token::StellarAssetClient,
+    schedules: Vec<VestingParams>,
+) -> TokenVestingClient<'a> {
+    let address = e.register(TokenVesting, (admin, &token.address, schedules.clone()));
+    let total: i128 = schedules.iter().map(|s| s.amount).sum();
+    asset.mint(&address, &total);
+    TokenVestingClient::new(e, &address)
+}
+
+fn set_timestamp(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn linear_vesting_with_cliff() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client =
+        create_vesting(&e, &admin, &token, &asset, Vec::from_array(&e, [params(&alice, 1000, false)]));
+
+    // Nothing before the cliff
+    set_timestamp(&e, START + CLIFF - 1);
+    assert_eq!(client.releasable(&alice), 0);
+
+    // Linear after the cliff
+    set_timestamp(&e, START + CLIFF);
+    assert_eq!(client.releasable(&alice), 100);
+    set_timestamp(&e, START + DURATION / 2);
+    assert_eq!(client.releasable(&alice), 500);
+
+    assert_eq!(client.release(&alice), 500);
+    assert_eq!(token.balance(&alice), 500);
+    assert_eq!(client.releasable(&alice), 0);
+
+    // Everything after the end
+    set_timestamp(&e, START + DURATION + 1);
+    assert_eq!(client.vested_amount(&alice, &(START + DURATION)), 1000);
+    assert_eq!(client.release(&alice), 500);
+    assert_eq!(token.balance(&alice), 1000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn multiple_beneficiaries() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let schedules = Vec::from_array(&e, [params(&alice, 1000, false), params(&bob, 3000, true)]);
+    let client = create_vesting(&e, &admin, &token, &asset, schedules);
+
+    assert_eq!(client.beneficiaries(), Vec::from_array(&e, [alice.clone(), bob.clone()]));
+
+    set_timestamp(&e, START + DURATION / 4);
+    assert_eq!(client.release(&alice), 250);
+    assert_eq!(client.release(&bob), 750);
+    assert_eq!(token.balance(&client.address), 3000);
+}
+
+#[test]
+fn revoke_returns_unvested_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client =
+        create_vesting(&e, &admin, &token, &asset, Vec::from_array(&e, [params(&alice, 1000, true)]));
+
+    set_timestamp(&e, START + DURATION * 3 / 10);
+    assert_eq!(client.revoke(&alice), 700);
+    assert_eq!(token.balance(&admin), 700);
+
+    // Vested tokens stay claimable and nothing more vests
+    set_timestamp(&e, START + DURATION);
+    assert_eq!(client.releasable(&alice), 300);
+    assert_eq!(client.release(&alice), 300);
+    assert_eq!(token.balance(&client.address), 0);
+    assert!(client.get_schedule(&alice).revoked);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn revoke_non_revocable_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client =
+        create_vesting(&e, &admin, &token, &asset, Vec::from_array(&e, [params(&alice, 1000, false)]));
+
+    client.revoke(&alice);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn revoke_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client =
+        create_vesting(&e, &admin, &token, &asset, Vec::from_array(&e, [params(&alice, 1000, true)]));
+
+    client.revoke(&alice);
+    client.revoke(&alice);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn release_before_cliff_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client =
+        create_vesting(&e, &admin, &token, &asset, Vec::from_array(&e, [params(&alice, 1000, false)]));
+
+    set_timestamp(&e, START);
+    client.release(&alice);
+}
+
+#[test]
+fn add_schedule_pulls_funds_from_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client = create_vesting(&e, &admin, &token, &asset, Vec::new(&e));
+
+    asset.mint(&admin, &1000);
+    client.add_schedule(&params(&alice, 1000, false));
+    assert_eq!(token.balance(&admin), 0);
+    assert_eq!(token.balance(&client.address), 1000);
+    assert_eq!(client.get_schedule(&alice).total_amount, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn duplicate_beneficiary_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let schedules = Vec::from_array(&e, [params(&alice, 1000, false), params(&alice, 500, false)]);
+    create_vesting(&e, &admin, &token, &asset, schedules);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn cliff_longer_than_duration_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let mut schedule = params(&alice, 1000, false);
+    schedule.cliff_seconds = DURATION + 1;
+    create_vesting(&e, &admin, &token, &asset, Vec::from_array(&e, [schedule]));
+}
+
+#[test]
+fn max_amount_vests_without_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let (token, asset) = create_token(&e, &admin);
+    let client = create_vesting(
+        &e,
+        &admin,
+        &token,
+        &asset,
+        Vec::from_array(&e, [params(&alice, i128::MAX, false)]),
+    );
+
+    // `i128::MAX * elapsed` alone would overflow
+    set_timestamp(&e, START + DURATION / 2);
+    assert_eq!(client.releasable(&alice), i128::MAX / 2);
+    assert_eq!(client.release(&alice), i128::MAX / 2);
+
+    // floor(i128::MAX * 999 / 1000), one second before the end
+    let vested = i128::MAX / 1000 * 999 + i128::MAX % 1000 * 999 / 1000;
+    assert_eq!(client.vested_amount(&alice, &(START + DURATION - 1)), vested);
+
+    set_timestamp(&e, START + DURATION);
+    assert_eq!(client.release(&alice), i128::MAX - i128::MAX / 2);
+    assert_eq!(token.balance(&alice), i128::MAX);
+}
@@ -0,0 +1,60 @@
+//! # Mock Vault Strategy
+//!
+//! Implements the `fungible-vault` strategy interface (`deposit`,
+//! `withdraw`, `total_value`) without earning any yield. It simply holds the
+//! assets the vault allocates to it and reports its balance as its value, so
+//! yield or losses can be simulated by transferring assets to or from it.
+//!
+//! Meant for tests and local networks only.
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env};
+
+#[contracttype]
+pub enum DataKey {
+    Vault,
+    Asset,
+}
+
+#[contract]
+pub struct MockStrategyContract;
+
+#[contractimpl]
+impl MockStrategyContract {
+    pub fn __constructor(e: &Env, vault: Address, asset: Address) {
+        e.storage().instance().set(&DataKey::Vault, &vault);
+        e.storage().instance().set(&DataKey::Asset, &asset);
+    }
+
+    /// The vault transfers the assets before calling, so there is nothing
+    /// to do beyond checking the caller.
+    pub fn deposit(e: &Env, _amount: i128) {
+        get_vault(e).require_auth();
+    }
+
+    /// Sends `amount` of the asset back to the vault.
+    pub fn withdraw(e: &Env, amount: i128) {
+        let vault = get_vault(e);
+        vault.require_auth();
+        asset_client(e).transfer(&e.current_contract_address(), &vault, &amount);
+    }
+
+    /// Returns the strategy's balance of the asset.
+    pub fn total_value(e: &Env) -> i128 {
+        asset_client(e).balance(&e.current_contract_address())
+    }
+
+    pub fn vault(e: &Env) -> Address {
+        get_vault(e)
+    }
+
+    pub fn asset(e: &Env) -> Address {
+        e.storage().instance().get(&DataKey::Asset).expect("asset should be set")
+    }
+}
+
+fn get_vault(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Vault).expect("vault should be set")
+}
+
+fn asset_client(e: &Env) -> token::Client<'_> {
+    token::Client::new(e, &MockStrategyContract::asset(e))
+}